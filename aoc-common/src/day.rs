@@ -0,0 +1,204 @@
+use std::{
+    any::Any,
+    fmt,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use crate::{input, Error, Generator, Result, Rng, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One = 1,
+    Two = 2,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl TryFrom<u8> for Part {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(Error::invalid(format!("no part {}", value))),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", *self as u8)
+    }
+}
+
+/// One part solved by [`Day::run`].
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    /// Time spent in the part itself; reading and parsing the input isn't
+    /// included since both parts share it.
+    pub elapsed: Duration,
+    pub input_hash: u64,
+}
+
+/// Parsed input of any day, as handed around by the runner.
+pub type Parsed = Box<dyn Any>;
+
+type Generate = fn(&mut Rng, usize, &mut dyn Write) -> std::io::Result<()>;
+
+/// One day of the calendar as seen by the runner. Built from a [`Solution`]
+/// with [`Day::new`], which erases the day's input and answer types.
+pub struct Day {
+    pub number: u8,
+    pub title: &'static str,
+    pub manifest_dir: &'static str,
+    parse: fn(&mut dyn Read) -> Result<Parsed>,
+    part_one: fn(&dyn Any) -> Result<String>,
+    part_two: fn(&dyn Any) -> Result<String>,
+    generate: Option<Generate>,
+}
+
+fn parse<S: Solution>(reader: &mut dyn Read) -> Result<Parsed>
+where
+    S::Input: 'static,
+{
+    Ok(Box::new(S::parse(reader)?))
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> Result<&S::Input>
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .ok_or_else(|| Error::invalid(format!("input wasn't parsed by day {}", S::DAY)))
+}
+
+fn part_one<S: Solution>(input: &dyn Any) -> Result<String>
+where
+    S::Input: 'static,
+{
+    Ok(S::part_one(downcast::<S>(input)?)?.to_string())
+}
+
+fn part_two<S: Solution>(input: &dyn Any) -> Result<String>
+where
+    S::Input: 'static,
+{
+    Ok(S::part_two(downcast::<S>(input)?)?.to_string())
+}
+
+impl Day {
+    pub const fn new<S: Solution>(manifest_dir: &'static str) -> Day
+    where
+        S::Input: 'static,
+    {
+        Day {
+            number: S::DAY,
+            title: S::TITLE,
+            manifest_dir,
+            parse: parse::<S>,
+            part_one: part_one::<S>,
+            part_two: part_two::<S>,
+            generate: None,
+        }
+    }
+
+    pub const fn with_generator<G: Generator>(mut self) -> Day {
+        self.generate = Some(G::generate);
+        self
+    }
+
+    pub fn default_input(&self) -> PathBuf {
+        input::default_path(self.manifest_dir)
+    }
+
+    pub fn parse(&self, reader: &mut dyn Read) -> Result<Parsed> {
+        (self.parse)(reader)
+    }
+
+    pub fn solve(&self, part: Part, input: &Parsed) -> Result<String> {
+        match part {
+            Part::One => (self.part_one)(input.as_ref()),
+            Part::Two => (self.part_two)(input.as_ref()),
+        }
+    }
+
+    pub fn generate(&self, rng: &mut Rng, size: usize, out: &mut dyn Write) -> Result<()> {
+        let generate = self
+            .generate
+            .ok_or_else(|| Error::invalid(format!("day {} has no generator", self.number)))?;
+        Ok(generate(rng, size, out)?)
+    }
+
+    /// Solves the requested parts, parsing the input only once, and hands
+    /// every answer to `report` as soon as it's known.
+    pub fn run<P: AsRef<Path>>(
+        &self,
+        parts: &[Part],
+        path: P,
+        mut report: impl FnMut(&Solved),
+    ) -> Result<()> {
+        let path = path.as_ref();
+        let data = input::read_bytes(input::open(path)?)?;
+        let input = self
+            .parse(&mut data.as_slice())
+            .map_err(|err| err.in_file(path))?;
+        let input_hash = input::hash(&data);
+
+        for part in parts {
+            let start = Instant::now();
+            // Some days only parse their lines while solving.
+            let answer = self.solve(*part, &input).map_err(|err| err.in_file(path))?;
+            report(&Solved {
+                day: self.number,
+                part: *part,
+                answer,
+                elapsed: start.elapsed(),
+                input_hash,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Prints an answer in the runner's format. Answers spanning several lines
+/// start on the line after the header.
+pub fn print_answer(solved: &Solved) {
+    let (day, part, answer) = (solved.day, solved.part, &solved.answer);
+    if answer.contains('\n') {
+        println!("Day {:02} part {}:", day, part);
+        println!("{}", answer.trim_end_matches('\n'));
+    } else {
+        println!("Day {:02} part {}: {}", day, part, answer);
+    }
+}
+
+/// Exit status of a binary, with the error printed to stderr the way it's
+/// meant to be read; a parse error gets its caret view.
+pub fn exit(result: Result<()>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Entry point shared by the per-day binaries.
+pub fn main(day: &Day) -> ExitCode {
+    let path = std::env::args_os()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| day.default_input());
+    exit(day.run(&Part::ALL, path, print_answer))
+}
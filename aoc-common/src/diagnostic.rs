@@ -0,0 +1,238 @@
+use std::{
+    fmt,
+    ops::Range,
+    path::{Path, PathBuf},
+    str::{FromStr, SplitWhitespace},
+};
+
+/// A parse error pointing at the offending text of one input line.
+///
+/// Parsers of a single token or line build it against the text they were
+/// given; callers that know more re-anchor it with [`Diagnostic::within`],
+/// [`Diagnostic::at_line`] and [`Diagnostic::in_file`] on the way up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub file: Option<PathBuf>,
+    /// 1-based; 0 while the line isn't known yet.
+    pub line: usize,
+    /// The whole line the error is on.
+    pub source: String,
+    /// Bytes of `source` under the caret, empty when something is missing.
+    pub span: Range<usize>,
+    pub message: String,
+    pub expected: Option<String>,
+}
+
+/// Byte offset of `part` in `outer`, if it's a slice of it.
+fn offset_in(outer: &str, part: &str) -> Option<usize> {
+    let start = (part.as_ptr() as usize).checked_sub(outer.as_ptr() as usize)?;
+    (start + part.len() <= outer.len()).then_some(start)
+}
+
+impl Diagnostic {
+    /// Error about the bytes `span` of `source`.
+    pub fn new(source: &str, span: Range<usize>, message: impl Into<String>) -> Self {
+        Diagnostic {
+            file: None,
+            line: 0,
+            source: source.to_string(),
+            span,
+            message: message.into(),
+            expected: None,
+        }
+    }
+
+    /// Error about `token`, which has to be a slice of `source`; anything
+    /// else is reported against the whole line.
+    pub fn token(source: &str, token: &str, message: impl Into<String>) -> Self {
+        match offset_in(source, token) {
+            Some(start) => Diagnostic::new(source, start..start + token.len(), message),
+            None => Diagnostic::new(source, 0..source.len(), message),
+        }
+    }
+
+    /// `token` isn't what was `expected` there.
+    pub fn unexpected(source: &str, token: &str, expected: impl Into<String>) -> Self {
+        Diagnostic::token(source, token, format!("unexpected {:?}", token)).expected(expected)
+    }
+
+    /// The line ended before something `expected`.
+    pub fn missing(source: &str, expected: impl Into<String>) -> Self {
+        let expected = expected.into();
+        Diagnostic::new(
+            source,
+            source.len()..source.len(),
+            format!("missing {}", expected),
+        )
+        .expected(expected)
+    }
+
+    pub fn expected(mut self, expected: impl Into<String>) -> Self {
+        self.expected = Some(expected.into());
+        self
+    }
+
+    /// 1-based, counted in characters.
+    pub fn column(&self) -> usize {
+        self.source[..self.span.start].chars().count() + 1
+    }
+
+    /// The offending text.
+    pub fn text(&self) -> &str {
+        &self.source[self.span.clone()]
+    }
+
+    /// Moves an error about `part` to the `source` it was cut from.
+    pub fn within(mut self, source: &str, part: &str) -> Self {
+        self.span = match offset_in(source, part).or_else(|| source.find(part)) {
+            Some(start) => start + self.span.start..start + self.span.end,
+            None => 0..source.len(),
+        };
+        self.source = source.to_string();
+        self
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    pub fn in_file(mut self, file: &Path) -> Self {
+        self.file = Some(file.to_path_buf());
+        self
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.file, self.line) {
+            (Some(file), 0) => write!(f, "{}: ", file.display())?,
+            (Some(file), line) => write!(f, "{}:{}:{}: ", file.display(), line, self.column())?,
+            (None, 0) => (),
+            (None, line) => write!(f, "line {}, column {}: ", line, self.column())?,
+        }
+        writeln!(f, "{}", self.message)?;
+
+        let number = if self.line > 0 {
+            self.line.to_string()
+        } else {
+            String::new()
+        };
+        let gutter = " ".repeat(number.len());
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", number, self.source)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.column() - 1),
+            "^".repeat(self.text().chars().count().max(1))
+        )?;
+        if let Some(expected) = &self.expected {
+            write!(f, " expected {}", expected)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+/// Whitespace-separated tokens of a line, with errors pointing into it.
+pub struct Tokens<'a> {
+    source: &'a str,
+    tokens: SplitWhitespace<'a>,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Tokens {
+            source,
+            tokens: source.split_whitespace(),
+        }
+    }
+
+    pub fn next(&mut self, expected: &str) -> Result<&'a str, Diagnostic> {
+        self.tokens
+            .next()
+            .ok_or_else(|| Diagnostic::missing(self.source, expected))
+    }
+
+    /// Skips a fixed word, like the `from` in `move 1 from 2 to 3`.
+    pub fn keyword(&mut self, keyword: &str) -> Result<(), Diagnostic> {
+        let expected = format!("{:?}", keyword);
+        match self.next(&expected)? {
+            token if token == keyword => Ok(()),
+            token => Err(Diagnostic::unexpected(self.source, token, expected)),
+        }
+    }
+
+    /// Parses the next token with a std [`FromStr`], e.g. a number.
+    pub fn parse<T: FromStr>(&mut self, expected: &str) -> Result<T, Diagnostic> {
+        let token = self.next(expected)?;
+        token
+            .parse()
+            .map_err(|_| Diagnostic::unexpected(self.source, token, expected))
+    }
+
+    /// Parses the next token with a [`FromStr`] that reports diagnostics of
+    /// its own.
+    pub fn read<T: FromStr<Err = Diagnostic>>(&mut self, expected: &str) -> Result<T, Diagnostic> {
+        let token = self.next(expected)?;
+        token
+            .parse()
+            .map_err(|err: Diagnostic| err.within(self.source, token))
+    }
+
+    /// Fails if anything but whitespace is left.
+    pub fn end(mut self) -> Result<(), Diagnostic> {
+        match self.tokens.next() {
+            Some(token) => Err(Diagnostic::unexpected(
+                self.source,
+                token,
+                "the end of the line",
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn caret_under_token() {
+        let mut tokens = Tokens::new("move x from 1 to 2");
+        tokens.keyword("move").unwrap();
+        let err = tokens
+            .parse::<u32>("an amount")
+            .unwrap_err()
+            .at_line(4)
+            .in_file(Path::new("data/input"));
+        assert_eq!(
+            err.to_string(),
+            "data/input:4:6: unexpected \"x\"\n  |\n4 | move x from 1 to 2\n  |      ^ expected an amount"
+        );
+    }
+
+    #[test]
+    fn missing_token_points_past_the_end() {
+        let mut tokens = Tokens::new("A");
+        tokens.next("the opponent's move").unwrap();
+        let err = tokens.next("your move").unwrap_err();
+        assert_eq!((err.column(), err.text()), (2, ""));
+        assert_eq!(
+            err.to_string(),
+            "missing your move\n |\n | A\n |  ^ expected your move"
+        );
+    }
+
+    #[test]
+    fn within_shifts_the_column() {
+        let line = "2-4,6-x";
+        let part = &line[4..];
+        let err = Diagnostic::unexpected(part, &part[2..], "a number").within(line, part);
+        assert_eq!((err.column(), err.text()), (7, "x"));
+        assert_eq!(err.source, line);
+    }
+}
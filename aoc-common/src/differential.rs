@@ -0,0 +1,36 @@
+//! Differential testing of optimized solvers against the naive ones they
+//! replaced. Inputs come from proptest strategies, so a disagreement is
+//! shrunk to the smallest input that still shows it.
+
+use std::fmt::Debug;
+
+pub use proptest;
+use proptest::{
+    prop_assert_eq,
+    strategy::Strategy,
+    test_runner::{Config, TestRunner},
+};
+
+/// Runs `naive` and `fast` on inputs drawn from `strategy` and panics with
+/// the minimal counterexample if they ever disagree. `PROPTEST_CASES`
+/// overrides how many inputs are tried.
+pub fn assert_agree<S, T, N, F>(strategy: S, naive: N, fast: F)
+where
+    S: Strategy,
+    S::Value: Debug,
+    T: PartialEq + Debug,
+    N: Fn(&S::Value) -> T,
+    F: Fn(&S::Value) -> T,
+{
+    let mut runner = TestRunner::new(Config {
+        failure_persistence: None,
+        ..Config::default()
+    });
+    let result = runner.run(&strategy, |input| {
+        prop_assert_eq!(naive(&input), fast(&input), "naive (left) vs fast (right)");
+        Ok(())
+    });
+    if let Err(err) = result {
+        panic!("{}", err);
+    }
+}
@@ -0,0 +1,58 @@
+use std::{fmt, io, path::Path};
+
+use crate::Diagnostic;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse(Box<Diagnostic>),
+    Invalid(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn invalid(message: impl Into<String>) -> Self {
+        Error::Invalid(message.into())
+    }
+
+    /// Names the file a parse error came from.
+    pub fn in_file(self, file: &Path) -> Self {
+        match self {
+            Error::Parse(diagnostic) => Error::Parse(Box::new(diagnostic.in_file(file))),
+            err => err,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "couldn't read input: {}", err),
+            Error::Parse(diagnostic) => write!(f, "{}", diagnostic),
+            Error::Invalid(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Parse(diagnostic) => Some(diagnostic.as_ref()),
+            Error::Invalid(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<Diagnostic> for Error {
+    fn from(diagnostic: Diagnostic) -> Self {
+        Error::Parse(Box::new(diagnostic))
+    }
+}
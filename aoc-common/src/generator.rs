@@ -0,0 +1,10 @@
+use std::io::{self, Write};
+
+use crate::Rng;
+
+/// Writes random puzzle inputs for stress-testing a day's solver.
+pub trait Generator {
+    /// Writes a valid input scaled by `size`, which means whatever is
+    /// natural for the day: elves, rounds, moves, forest side...
+    fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()>;
+}
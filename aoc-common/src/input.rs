@@ -0,0 +1,146 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::{Diagnostic, Error, Result};
+
+/// Path that selects stdin instead of a file.
+pub const STDIN: &str = "-";
+
+/// Where a day keeps its puzzle input, relative to the crate root.
+pub fn default_path(manifest_dir: &str) -> PathBuf {
+    Path::new(manifest_dir).join("data").join("input")
+}
+
+pub fn open<P: AsRef<Path>>(path: P) -> Result<Box<dyn Read>> {
+    let path = path.as_ref();
+    if path == Path::new(STDIN) {
+        return Ok(Box::new(io::stdin()));
+    }
+    Ok(Box::new(File::open(path)?))
+}
+
+pub fn read_string<R: Read>(mut reader: R) -> Result<String> {
+    let mut data = String::new();
+    reader.read_to_string(&mut data)?;
+    Ok(data)
+}
+
+pub fn read_bytes<R: Read>(mut reader: R) -> Result<Vec<u8>> {
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+    Ok(data)
+}
+
+/// 64-bit FNV-1a of an input, to tell which input an answer belongs to.
+pub fn hash(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+pub fn lines<R: Read>(reader: R) -> Result<Vec<String>> {
+    BufReader::new(reader)
+        .lines()
+        .map(|l| l.map_err(Error::from))
+        .collect()
+}
+
+/// Groups lines separated by blank lines. The last group is kept whether or
+/// not the input ends with a blank line.
+pub fn paragraphs<R: Read>(reader: R) -> Result<Vec<Vec<String>>> {
+    Ok(numbered_paragraphs(reader)?
+        .into_iter()
+        .map(|paragraph| paragraph.into_iter().map(|(_, line)| line).collect())
+        .collect())
+}
+
+/// Like [`paragraphs`], with the 1-based number of every line.
+pub fn numbered_paragraphs<R: Read>(reader: R) -> Result<Vec<Vec<(usize, String)>>> {
+    let mut paragraphs: Vec<Vec<(usize, String)>> = Vec::new();
+    let mut paragraph: Vec<(usize, String)> = Vec::new();
+
+    for (idx, line) in lines(reader)?.into_iter().enumerate() {
+        if line.trim().is_empty() {
+            if !paragraph.is_empty() {
+                paragraphs.push(std::mem::take(&mut paragraph));
+            }
+            continue;
+        }
+        paragraph.push((idx + 1, line));
+    }
+    if !paragraph.is_empty() {
+        paragraphs.push(paragraph);
+    }
+
+    Ok(paragraphs)
+}
+
+/// Reads a block of characters row by row, mapping each one with `cell`.
+/// Every row has to be as long as the first; blank lines are skipped.
+pub fn grid<R, T, F>(reader: R, mut cell: F) -> Result<Vec<Vec<T>>>
+where
+    R: Read,
+    F: FnMut(char) -> Option<T>,
+{
+    let mut rows: Vec<Vec<T>> = Vec::new();
+
+    for (idx, line) in lines(reader)?.iter().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        let row = line
+            .char_indices()
+            .map(|(start, c)| {
+                cell(c).ok_or_else(|| {
+                    Diagnostic::new(
+                        line,
+                        start..start + c.len_utf8(),
+                        format!("unexpected {:?}", c),
+                    )
+                    .at_line(idx + 1)
+                    .into()
+                })
+            })
+            .collect::<Result<Vec<T>>>()?;
+
+        // A short row points past its end, a long one at its extra cells.
+        if let Some(width) = rows.first().map(Vec::len).filter(|&w| w != row.len()) {
+            let message = format!(
+                "the row has {} cells, the first one has {}",
+                row.len(),
+                width
+            );
+            let span = width.min(row.len())..row.len();
+            return Err(Diagnostic::new(line, span, message).at_line(idx + 1).into());
+        }
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_is_fnv1a() {
+        assert_eq!(hash(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(hash(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn ragged_grid_rows() {
+        let digit = |c: char| c.to_digit(10);
+        assert_eq!(grid("12\n\n34\n".as_bytes(), digit).unwrap().len(), 2);
+        for (input, column) in [("123\n12\n", 3), ("12\n1234\n", 3)] {
+            let Err(Error::Parse(err)) = grid(input.as_bytes(), digit) else {
+                panic!("{:?} is ragged", input);
+            };
+            assert_eq!((err.line, err.column()), (2, column));
+        }
+    }
+}
@@ -0,0 +1,16 @@
+pub mod day;
+mod diagnostic;
+#[cfg(feature = "differential")]
+pub mod differential;
+mod error;
+mod generator;
+pub mod input;
+mod rng;
+mod solution;
+
+pub use day::{Day, Part, Solved};
+pub use diagnostic::{Diagnostic, Tokens};
+pub use error::{Error, Result};
+pub use generator::Generator;
+pub use rng::Rng;
+pub use solution::Solution;
@@ -0,0 +1,49 @@
+/// Small seeded generator (SplitMix64). Hand-rolled so a seed produces the
+/// same input on every machine and dependency version.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `low..=high`.
+    pub fn between(&mut self, low: u64, high: u64) -> u64 {
+        let span = high - low;
+        if span == u64::MAX {
+            return self.next_u64();
+        }
+        low + ((self.next_u64() as u128 * (span + 1) as u128) >> 64) as u64
+    }
+
+    /// Uniform index into a collection of `len` items; there has to be one.
+    pub fn index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "no index into an empty collection");
+        self.between(0, len as u64 - 1) as usize
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.index(idx + 1));
+        }
+    }
+}
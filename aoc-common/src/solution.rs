@@ -0,0 +1,17 @@
+use std::{fmt::Display, io::Read};
+
+use crate::Result;
+
+/// A day's puzzle: the input is parsed once and shared by both parts.
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse<R: Read>(reader: R) -> Result<Self::Input>;
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne>;
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo>;
+}
@@ -0,0 +1,206 @@
+use std::{
+    fmt, fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{input, Day, Error, Part, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{alloc, Format};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    const ALL: [Stage; 3] = [Stage::Parse, Stage::Part1, Stage::Part2];
+}
+
+impl From<Part> for Stage {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Stage::Part1,
+            Part::Two => Stage::Part2,
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part1 => write!(f, "part1"),
+            Stage::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+/// Timing of one stage of one day over every run. Allocation counts are the
+/// median per run.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: u8,
+    pub stage: Stage,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    pub allocations: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Report {
+    pub runs: usize,
+    pub timings: Vec<Timing>,
+}
+
+#[derive(Default)]
+struct Samples {
+    durations: Vec<u64>,
+    allocations: Vec<usize>,
+}
+
+impl Samples {
+    fn measure<T>(&mut self, f: impl FnOnce() -> T) -> T {
+        let allocations = alloc::allocations();
+        let start = Instant::now();
+        let result = f();
+        self.durations.push(start.elapsed().as_nanos() as u64);
+        self.allocations.push(alloc::allocations() - allocations);
+        result
+    }
+
+    fn timing(mut self, day: u8, stage: Stage) -> Timing {
+        self.durations.sort_unstable();
+        self.allocations.sort_unstable();
+        Timing {
+            day,
+            stage,
+            min_ns: self.durations[0],
+            median_ns: percentile(&self.durations, 0.5),
+            p95_ns: percentile(&self.durations, 0.95),
+            allocations: percentile(&self.allocations, 0.5),
+        }
+    }
+}
+
+/// Nearest-rank percentile of sorted, non-empty samples.
+fn percentile<T: Copy>(sorted: &[T], p: f64) -> T {
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn bench_day(day: &Day, input: &str, runs: usize) -> Result<Vec<Timing>> {
+    let mut samples: [Samples; 3] = Default::default();
+
+    for _ in 0..runs {
+        let parsed = samples[0].measure(|| day.parse(&mut input.as_bytes()))?;
+        for (idx, part) in Part::ALL.into_iter().enumerate() {
+            samples[idx + 1].measure(|| day.solve(part, &parsed))?;
+        }
+    }
+
+    Ok(samples
+        .into_iter()
+        .zip(Stage::ALL)
+        .map(|(samples, stage)| samples.timing(day.number, stage))
+        .collect())
+}
+
+pub fn bench<P: AsRef<Path>>(days: &[(&Day, P)], runs: usize) -> Result<Report> {
+    if runs == 0 {
+        return Err(Error::invalid("at least one run is needed"));
+    }
+    let mut timings = Vec::new();
+    for (day, path) in days {
+        let input = input::read_string(input::open(path)?)?;
+        timings.append(&mut bench_day(day, &input, runs)?);
+    }
+    Ok(Report { runs, timings })
+}
+
+pub fn load_baseline(path: &Path) -> Result<Report> {
+    let data = input::read_string(input::open(path)?)?;
+    serde_json::from_str(&data)
+        .map_err(|err| Error::invalid(format!("{}: {}", path.display(), err)))
+}
+
+pub fn save(report: &Report, path: &Path) -> Result<()> {
+    let data = serde_json::to_string_pretty(report)
+        .map_err(|err| Error::invalid(format!("couldn't serialize report: {}", err)))?;
+    fs::write(path, data + "\n")?;
+    Ok(())
+}
+
+/// Relative change of the median against the baseline, if it has this stage.
+fn change(timing: &Timing, baseline: Option<&Report>) -> Option<f64> {
+    let previous = baseline?
+        .timings
+        .iter()
+        .find(|t| t.day == timing.day && t.stage == timing.stage)?;
+    Some(timing.median_ns as f64 / previous.median_ns.max(1) as f64 - 1.0)
+}
+
+fn duration(ns: u64) -> String {
+    format!("{:.1?}", Duration::from_nanos(ns))
+}
+
+/// Prints the report and returns how many stages regressed by more than
+/// `threshold` against the baseline.
+pub fn print(
+    report: &Report,
+    baseline: Option<&Report>,
+    threshold: f64,
+    format: Format,
+) -> Result<usize> {
+    let mut regressions = 0;
+
+    if let Format::Json = format {
+        let data = serde_json::to_string_pretty(report)
+            .map_err(|err| Error::invalid(format!("couldn't serialize report: {}", err)))?;
+        println!("{}", data);
+    } else {
+        println!(
+            "{:>3}  {:<6} {:>10} {:>10} {:>10} {:>10}  baseline",
+            "day", "stage", "min", "median", "p95", "allocs"
+        );
+    }
+
+    for timing in &report.timings {
+        let change = change(timing, baseline);
+        let regressed = change.is_some_and(|change| change > threshold);
+        if regressed {
+            regressions += 1;
+        }
+
+        match format {
+            Format::Json if regressed => eprintln!(
+                "Day {:02} {} regressed by {:+.1}%",
+                timing.day,
+                timing.stage,
+                change.unwrap_or_default() * 100.0
+            ),
+            Format::Json => (),
+            Format::Text => println!(
+                "{:>3}  {:<6} {:>10} {:>10} {:>10} {:>10}  {}",
+                format!("{:02}", timing.day),
+                timing.stage,
+                duration(timing.min_ns),
+                duration(timing.median_ns),
+                duration(timing.p95_ns),
+                timing.allocations,
+                match change {
+                    Some(change) if regressed => format!("{:+.1}% REGRESSED", change * 100.0),
+                    Some(change) => format!("{:+.1}%", change * 100.0),
+                    None => "-".to_string(),
+                }
+            ),
+        }
+    }
+
+    Ok(regressions)
+}
@@ -0,0 +1,42 @@
+use aoc_common::Day;
+
+pub const DAYS: [&Day; 11] = [
+    &day_01::DAY,
+    &day_02::DAY,
+    &day_03::DAY,
+    &day_04::DAY,
+    &day_05::DAY,
+    &day_06::DAY,
+    &day_07::DAY,
+    &day_08::DAY,
+    &day_09::DAY,
+    &day_10::DAY,
+    &day_11::DAY,
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().copied().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Part, Rng};
+
+    use super::*;
+
+    #[test]
+    fn generated_inputs_solve() {
+        for day in DAYS {
+            for seed in 0..4 {
+                let mut input = Vec::new();
+                day.generate(&mut Rng::new(seed), 20, &mut input).unwrap();
+                let parsed = day.parse(&mut input.as_slice()).unwrap();
+                for part in Part::ALL {
+                    if let Err(err) = day.solve(part, &parsed) {
+                        panic!("day {} part {}, seed {}: {}", day.number, part, seed, err);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use aoc_common::{
+    day::{self, print_answer},
+    Error, Part, Result, Rng, Solved,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+
+mod alloc;
+mod bench;
+mod days;
+mod verify;
+
+#[global_allocator]
+static ALLOCATOR: alloc::CountingAllocator = alloc::CountingAllocator;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every day with --all
+    Run(RunArgs),
+    /// Check every solver against the registry of known answers
+    Verify(VerifyArgs),
+    /// Time parsing and both parts of every day
+    Bench(BenchArgs),
+    /// Write a random but valid puzzle input
+    Gen(GenArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to solve
+    #[arg(short, long, required_unless_present = "all")]
+    day: Option<u8>,
+    /// Part to solve, both when omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Puzzle input, `-` for stdin; defaults to the day's data/input
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+    /// Solve every day with its default input
+    #[arg(short, long, conflicts_with = "day")]
+    all: bool,
+    /// `json` prints one object per answer and line
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify this day
+    #[arg(short, long)]
+    day: Option<u8>,
+    /// Answer registry to check against
+    #[arg(long, default_value = verify::REGISTRY)]
+    answers: PathBuf,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only benchmark this day
+    #[arg(short, long)]
+    day: Option<u8>,
+    /// Puzzle input, defaults to the day's data/input
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+    /// How many times each stage runs
+    #[arg(short, long, default_value_t = 10)]
+    runs: usize,
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Previous report to compare medians against
+    #[arg(short, long)]
+    baseline: Option<PathBuf>,
+    /// Slowdown over the baseline median that counts as a regression
+    #[arg(short, long, default_value_t = 0.2)]
+    threshold: f64,
+    /// Write the report as JSON, e.g. to use as the next baseline
+    #[arg(short, long)]
+    save: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    /// Day to generate an input for
+    #[arg(short, long)]
+    day: u8,
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// How big the input gets, e.g. `1e6`; the unit depends on the day
+    #[arg(long, value_parser = parse_size, default_value = "1000")]
+    size: usize,
+    /// Where to write the input instead of stdout
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+/// Sizes are easier to type in scientific notation.
+fn parse_size(value: &str) -> std::result::Result<usize, String> {
+    let size: f64 = value
+        .parse()
+        .map_err(|_| format!("{:?} isn't a number", value))?;
+    if size < 0.0 || size.fract() != 0.0 || size > usize::MAX as f64 {
+        return Err(format!("{:?} isn't a whole, positive size", value));
+    }
+    Ok(size as usize)
+}
+
+impl RunArgs {
+    fn parts(&self) -> Result<Vec<Part>> {
+        match self.part {
+            Some(part) => Ok(vec![Part::try_from(part)?]),
+            None => Ok(Part::ALL.to_vec()),
+        }
+    }
+}
+
+/// An answer as printed by `run --format json`.
+#[derive(Serialize)]
+struct JsonAnswer<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    elapsed_ms: f64,
+    input_hash: String,
+}
+
+fn print_json(solved: &Solved) {
+    let answer = JsonAnswer {
+        day: solved.day,
+        part: solved.part as u8,
+        answer: solved.answer.trim_end_matches('\n'),
+        elapsed_ms: solved.elapsed.as_secs_f64() * 1000.0,
+        input_hash: format!("{:016x}", solved.input_hash),
+    };
+    match serde_json::to_string(&answer) {
+        Ok(line) => println!("{}", line),
+        Err(err) => eprintln!("couldn't serialize answer: {}", err),
+    }
+}
+
+fn run(args: &RunArgs) -> Result<()> {
+    let parts = args.parts()?;
+    let report = match args.format {
+        Format::Text => print_answer,
+        Format::Json => print_json,
+    };
+
+    if args.all {
+        let mut failed = false;
+        for day in days::DAYS {
+            if let Err(err) = day.run(&parts, day.default_input(), report) {
+                eprintln!("Day {:02}: {}", day.number, err);
+                failed = true;
+            }
+        }
+        if failed {
+            return Err(Error::invalid("some days failed"));
+        }
+        return Ok(());
+    }
+
+    let number = args.day.unwrap_or_default();
+    let day = days::find(number).ok_or_else(|| Error::invalid(format!("no day {}", number)))?;
+    let input = args.input.clone().unwrap_or_else(|| day.default_input());
+    day.run(&parts, input, report)
+}
+
+fn run_verify(args: &VerifyArgs) -> Result<()> {
+    if verify::verify(&args.answers, args.day)? {
+        Ok(())
+    } else {
+        Err(Error::invalid("verification failed"))
+    }
+}
+
+fn run_bench(args: &BenchArgs) -> Result<()> {
+    let selected = match args.day {
+        Some(number) => {
+            let day =
+                days::find(number).ok_or_else(|| Error::invalid(format!("no day {}", number)))?;
+            let input = args.input.clone().unwrap_or_else(|| day.default_input());
+            vec![(day, input)]
+        }
+        None => days::DAYS
+            .iter()
+            .map(|day| (*day, day.default_input()))
+            .collect(),
+    };
+
+    let report = bench::bench(&selected, args.runs)?;
+    let baseline = args
+        .baseline
+        .as_deref()
+        .map(bench::load_baseline)
+        .transpose()?;
+    if let Some(path) = &args.save {
+        bench::save(&report, path)?;
+    }
+
+    let regressions = bench::print(&report, baseline.as_ref(), args.threshold, args.format)?;
+    if regressions > 0 {
+        return Err(Error::invalid(format!("{} stages regressed", regressions)));
+    }
+    Ok(())
+}
+
+fn run_gen(args: &GenArgs) -> Result<()> {
+    let day = days::find(args.day).ok_or_else(|| Error::invalid(format!("no day {}", args.day)))?;
+    let mut out: BufWriter<Box<dyn Write>> = BufWriter::new(match &args.output {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(io::stdout().lock()),
+    });
+    day.generate(&mut Rng::new(args.seed), args.size, &mut out)?;
+    Ok(out.flush()?)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match &cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => run_verify(args),
+        Command::Bench(args) => run_bench(args),
+        Command::Gen(args) => run_gen(args),
+    };
+    day::exit(result)
+}
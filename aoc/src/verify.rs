@@ -0,0 +1,131 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{input, Error, Part, Result};
+use serde::Deserialize;
+
+use crate::days;
+
+/// Known-correct answers, checked in at the workspace root.
+pub const REGISTRY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+#[derive(Deserialize)]
+struct Registry {
+    #[serde(rename = "answer", default)]
+    answers: Vec<Answer>,
+}
+
+/// One registry entry. `input` is relative to the registry file.
+#[derive(Deserialize)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    pub input: PathBuf,
+    pub answer: String,
+}
+
+enum Outcome {
+    Pass,
+    Fail(String),
+    Error(String),
+}
+
+pub fn load(path: &Path) -> Result<Vec<Answer>> {
+    let data = input::read_string(input::open(path)?)?;
+    let registry: Registry = toml::from_str(&data)
+        .map_err(|err| Error::invalid(format!("{}: {}", path.display(), err)))?;
+    Ok(registry.answers)
+}
+
+fn solve(answer: &Answer, root: &Path) -> Result<String> {
+    let day =
+        days::find(answer.day).ok_or_else(|| Error::invalid(format!("no day {}", answer.day)))?;
+    let part = Part::try_from(answer.part)?;
+    let path = root.join(&answer.input);
+    let parsed = day
+        .parse(&mut input::open(&path)?)
+        .map_err(|err| err.in_file(&path))?;
+    day.solve(part, &parsed).map_err(|err| err.in_file(&path))
+}
+
+fn check(answer: &Answer, root: &Path) -> Outcome {
+    match panic::catch_unwind(AssertUnwindSafe(|| solve(answer, root))) {
+        Ok(Ok(actual)) if normalize(&actual) == normalize(&answer.answer) => Outcome::Pass,
+        Ok(Ok(actual)) => Outcome::Fail(actual),
+        Ok(Err(err)) => Outcome::Error(err.to_string()),
+        Err(_) => Outcome::Error("solver panicked".to_string()),
+    }
+}
+
+/// Answers are compared without trailing newlines, which TOML multi-line
+/// strings make awkward to control.
+fn normalize(answer: &str) -> &str {
+    answer.trim_end_matches('\n')
+}
+
+fn print_diff(expected: &str, actual: &str) {
+    let expected: Vec<&str> = normalize(expected).lines().collect();
+    let actual: Vec<&str> = normalize(actual).lines().collect();
+
+    if expected.len() <= 1 && actual.len() <= 1 {
+        println!("    expected: {}", expected.first().unwrap_or(&""));
+        println!("    actual:   {}", actual.first().unwrap_or(&""));
+        return;
+    }
+
+    for idx in 0..expected.len().max(actual.len()) {
+        let expected_line = expected.get(idx);
+        let actual_line = actual.get(idx);
+        if expected_line == actual_line {
+            continue;
+        }
+        println!("    line {}:", idx + 1);
+        if let Some(line) = expected_line {
+            println!("      - {}", line);
+        }
+        if let Some(line) = actual_line {
+            println!("      + {}", line);
+        }
+    }
+}
+
+/// Runs every registered answer, optionally only for one day, and reports
+/// each result. Returns whether all of them passed.
+pub fn verify(registry: &Path, day: Option<u8>) -> Result<bool> {
+    let root = registry.parent().unwrap_or(Path::new("."));
+    let mut passed = 0;
+    let mut failed = 0;
+
+    for answer in load(registry)? {
+        if day.is_some_and(|day| day != answer.day) {
+            continue;
+        }
+        let label = format!(
+            "Day {:02} part {} [{}]",
+            answer.day,
+            answer.part,
+            answer.input.display()
+        );
+        match check(&answer, root) {
+            Outcome::Pass => {
+                passed += 1;
+                println!("{} ok", label);
+            }
+            Outcome::Fail(actual) => {
+                failed += 1;
+                println!("{} FAIL", label);
+                print_diff(&answer.answer, &actual);
+            }
+            Outcome::Error(err) => {
+                failed += 1;
+                println!("{} ERROR", label);
+                println!("    {}", err);
+            }
+        }
+    }
+
+    println!("{} passed, {} failed", passed, failed);
+    Ok(failed == 0)
+}
@@ -0,0 +1,20 @@
+use std::io::{self, Write};
+
+use aoc_common::{Generator, Rng};
+
+use crate::CalorieCounting;
+
+/// `size` is the number of elves.
+impl Generator for CalorieCounting {
+    fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+        for elf in 0..size {
+            if elf > 0 {
+                writeln!(out)?;
+            }
+            for _ in 0..rng.between(1, 15) {
+                writeln!(out, "{}", rng.between(1000, 60_000))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+use std::io::Read;
+
+use aoc_common::{input, Diagnostic, Result};
+
+/// One food item and the input line it was written on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ration {
+    pub calories: u32,
+    pub line: usize,
+}
+
+/// Everything one elf carries, in input order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    /// 1-based position in the input.
+    pub number: usize,
+    pub rations: Vec<Ration>,
+}
+
+impl Elf {
+    pub fn total(&self) -> u64 {
+        self.rations
+            .iter()
+            .map(|ration| u64::from(ration.calories))
+            .sum()
+    }
+
+    pub fn items(&self) -> usize {
+        self.rations.len()
+    }
+
+    /// The first of the biggest rations.
+    pub fn largest_ration(&self) -> Option<&Ration> {
+        self.rations
+            .iter()
+            .rev()
+            .max_by_key(|ration| ration.calories)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Inventory {
+    pub elves: Vec<Elf>,
+}
+
+impl Inventory {
+    /// Reads one ration per line, elves separated by blank lines. The last
+    /// elf counts whether or not the input ends with a blank line.
+    pub fn load<R: Read>(reader: R) -> Result<Inventory> {
+        let mut elves = Vec::new();
+        for (idx, paragraph) in input::numbered_paragraphs(reader)?.into_iter().enumerate() {
+            let mut rations = Vec::new();
+            for (line, text) in paragraph {
+                let calories = text.trim().parse().map_err(|_| {
+                    Diagnostic::unexpected(&text, text.trim(), "calories").at_line(line)
+                })?;
+                rations.push(Ration { calories, line });
+            }
+            elves.push(Elf {
+                number: idx + 1,
+                rations,
+            });
+        }
+        Ok(Inventory { elves })
+    }
+
+    /// Calories per elf, in input order.
+    pub fn totals(&self) -> Vec<u64> {
+        self.elves.iter().map(Elf::total).collect()
+    }
+
+    /// The elf with the most items; the first one on a tie.
+    pub fn most_items(&self) -> Option<&Elf> {
+        self.elves.iter().rev().max_by_key(|elf| elf.items())
+    }
+
+    /// The biggest single ration and who carries it; the first one on a tie.
+    pub fn largest_ration(&self) -> Option<(&Elf, &Ration)> {
+        self.elves
+            .iter()
+            .rev()
+            .filter_map(|elf| Some((elf, elf.largest_ration()?)))
+            .max_by_key(|(_, ration)| ration.calories)
+    }
+
+    /// Elves carrying more than `calories` in total.
+    pub fn carrying_over(&self, calories: u64) -> impl Iterator<Item = &Elf> {
+        self.elves.iter().filter(move |elf| elf.total() > calories)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../data/example");
+
+    #[test]
+    fn keeps_every_ration_and_the_last_elf() {
+        let inventory = Inventory::load(EXAMPLE.trim_end().as_bytes()).unwrap();
+        assert_eq!(inventory.totals(), [6000, 4000, 11000, 24000, 10000]);
+        assert_eq!(
+            inventory.elves[4].rations,
+            [Ration {
+                calories: 10000,
+                line: 14
+            }]
+        );
+    }
+
+    #[test]
+    fn queries() {
+        let inventory = Inventory::load(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(inventory.most_items().map(|elf| elf.number), Some(1));
+
+        let (elf, ration) = inventory.largest_ration().unwrap();
+        assert_eq!((elf.number, ration.calories, ration.line), (5, 10000, 14));
+
+        let numbers: Vec<usize> = inventory
+            .carrying_over(10000)
+            .map(|elf| elf.number)
+            .collect();
+        assert_eq!(numbers, [3, 4]);
+    }
+}
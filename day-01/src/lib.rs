@@ -0,0 +1,69 @@
+use std::io::Read;
+
+use aoc_common::{Day, Error, Result, Solution};
+
+mod generator;
+mod inventory;
+pub mod stats;
+pub mod teams;
+mod top;
+
+pub use inventory::{Elf, Inventory, Ration};
+pub use top::{stream_top_k, TopK};
+
+pub const DAY: Day =
+    Day::new::<CalorieCounting>(env!("CARGO_MANIFEST_DIR")).with_generator::<CalorieCounting>();
+
+pub struct CalorieCounting;
+
+fn top_k(inventory: &Inventory, k: usize) -> TopK {
+    let mut top = TopK::new(k);
+    top.extend(inventory.elves.iter().map(Elf::total));
+    top
+}
+
+fn solve(inventory: &Inventory, k: usize) -> Result<u64> {
+    top_k(inventory, k)
+        .sum()
+        .ok_or_else(|| Error::invalid(format!("the top {} elves carry too much to add up", k)))
+}
+
+impl Solution for CalorieCounting {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+
+    type Input = Inventory;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse<R: Read>(reader: R) -> Result<Self::Input> {
+        Inventory::load(reader)
+    }
+
+    fn part_one(inventory: &Self::Input) -> Result<u64> {
+        solve(inventory, 1)
+    }
+
+    fn part_two(inventory: &Self::Input) -> Result<u64> {
+        solve(inventory, 3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../data/example");
+
+    #[test]
+    fn part_one_example() {
+        let input = CalorieCounting::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(CalorieCounting::part_one(&input).unwrap(), 24000);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = CalorieCounting::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(CalorieCounting::part_two(&input).unwrap(), 45000);
+    }
+}
@@ -1,41 +1,191 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
-
-static DATA_FILE: &'static str = "./data.txt";
-
-fn load_calories(path: &str) -> Vec<u32> {
-    let file_path = Path::new(path);
-    let file = File::open(file_path).expect("Couldn't open file");
-
-    let buf = BufReader::new(file);
-    let mut all_elves: Vec<u32> = Vec::new();
-    let mut one_elf: u32 = 0;
-    let lines = buf.lines().map(|l| l.unwrap());
-    for line in lines {
-        match line.parse::<u32>() {
-            Err(_) => {
-                all_elves.push(one_elf);
-                one_elf = 0;
-            }
-            Ok(ration_calories) => one_elf = one_elf + ration_calories,
-        };
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use aoc_common::{
+    day::{self, print_answer},
+    input, Error, Part, Result,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use day_01::{
+    stats::{self, Stats},
+    teams::{self, Method},
+    Inventory, DAY,
+};
+
+#[derive(Parser)]
+#[command(name = "day-01", about = "Calorie Counting")]
+#[command(args_conflicts_with_subcommands = true)]
+struct Cli {
+    /// Puzzle input, `-` for stdin; defaults to data/input
+    input: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Read the input once, line by line, and print the k biggest elf totals
+    Top {
+        #[arg(short, default_value_t = 3)]
+        k: usize,
+        /// Puzzle input, `-` for stdin; defaults to data/input
+        input: Option<PathBuf>,
+    },
+    /// Summarize the per-elf totals, or export them as a table
+    Stats {
+        /// Puzzle input, `-` for stdin; defaults to data/input
+        input: Option<PathBuf>,
+        /// Number of histogram bars
+        #[arg(long, default_value_t = 10)]
+        bins: usize,
+        /// Write the per-elf table, instead of the report unless --output is given
+        #[arg(long, value_enum)]
+        export: Option<Export>,
+        /// Where to write the table
+        #[arg(short, long, requires = "export")]
+        output: Option<PathBuf>,
+    },
+    /// Split the elves into k teams carrying as evenly as possible
+    Teams {
+        #[arg(short, default_value_t = 3)]
+        k: usize,
+        /// Puzzle input, `-` for stdin; defaults to data/input
+        input: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Solver::Auto)]
+        method: Solver,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Solver {
+    /// Exact for small inputs, the better heuristic otherwise
+    Auto,
+    Exact,
+    Greedy,
+    /// Karmarkar-Karp
+    Kk,
+}
+
+impl From<Solver> for Method {
+    fn from(solver: Solver) -> Self {
+        match solver {
+            Solver::Auto => Method::Auto,
+            Solver::Exact => Method::Exact,
+            Solver::Greedy => Method::Greedy,
+            Solver::Kk => Method::KarmarkarKarp,
+        }
     }
-    all_elves
 }
 
-fn solve_1() -> u32 {
-    let calories = load_calories(DATA_FILE);
-    calories.iter().max().expect("Couldn't find max").to_owned()
+#[derive(Clone, Copy, ValueEnum)]
+enum Export {
+    Csv,
+    Json,
+}
+
+fn top(k: usize, path: PathBuf) -> Result<()> {
+    let top = day_01::stream_top_k(input::open(&path)?, k).map_err(|err| err.in_file(&path))?;
+    let total = top
+        .sum()
+        .ok_or_else(|| Error::invalid("the totals don't add up to a u64"))?;
+    for (rank, calories) in top.into_sorted().into_iter().enumerate() {
+        println!("{:>3}. {}", rank + 1, calories);
+    }
+    println!("total: {}", total);
+    Ok(())
 }
 
-fn solve_2() -> u32 {
-    let mut calories = load_calories(DATA_FILE);
-    calories.sort();
-    calories.iter().rev().take(3).sum()
+fn print_stats(stats: &Stats, histogram: &str) {
+    println!("elves:   {}", stats.count);
+    println!("min:     {}", stats.min);
+    println!("max:     {}", stats.max);
+    println!("mean:    {:.1}", stats.mean);
+    println!("median:  {:.1}", stats.median);
+    println!("std dev: {:.1}", stats.std_dev);
+    for (p, total) in &stats.percentiles {
+        println!("p{:<2}:     {}", p, total);
+    }
+    println!();
+    print!("{}", histogram);
+}
+
+fn run_stats(
+    path: PathBuf,
+    bins: usize,
+    export: Option<Export>,
+    output: Option<PathBuf>,
+) -> Result<()> {
+    let inventory = Inventory::load(input::open(&path)?).map_err(|err| err.in_file(&path))?;
+
+    if let Some(export) = export {
+        let rows = stats::rows(&inventory);
+        let mut out: BufWriter<Box<dyn Write>> = BufWriter::new(match &output {
+            Some(path) => Box::new(File::create(path)?),
+            None => Box::new(io::stdout().lock()),
+        });
+        match export {
+            Export::Csv => stats::write_csv(&rows, &mut out)?,
+            Export::Json => stats::write_json(&rows, &mut out)?,
+        }
+        out.flush()?;
+        if output.is_none() {
+            return Ok(());
+        }
+    }
+
+    let totals = inventory.totals();
+    let stats = Stats::new(&totals).ok_or_else(|| Error::invalid("there are no elves"))?;
+    print_stats(&stats, &stats::histogram(&totals, bins, 40));
+    Ok(())
+}
+
+fn run_teams(k: usize, path: PathBuf, method: Method) -> Result<()> {
+    let inventory = Inventory::load(input::open(&path)?).map_err(|err| err.in_file(&path))?;
+    let partition = teams::partition(&inventory, k, method)?;
+    for (idx, team) in partition.teams.iter().enumerate() {
+        let elves: Vec<String> = team.elves.iter().map(ToString::to_string).collect();
+        println!(
+            "team {:>2}: {} calories, elves {}",
+            idx + 1,
+            team.total,
+            elves.join(", ")
+        );
+    }
+    println!("spread: {}", partition.spread());
+    Ok(())
+}
+
+fn run(cli: Cli) -> Result<()> {
+    match cli.command {
+        None => DAY.run(
+            &Part::ALL,
+            cli.input.unwrap_or_else(|| DAY.default_input()),
+            print_answer,
+        ),
+        Some(Command::Top { k, input }) => top(k, input.unwrap_or_else(|| DAY.default_input())),
+        Some(Command::Stats {
+            input,
+            bins,
+            export,
+            output,
+        }) => run_stats(
+            input.unwrap_or_else(|| DAY.default_input()),
+            bins,
+            export,
+            output,
+        ),
+        Some(Command::Teams { k, input, method }) => run_teams(
+            k,
+            input.unwrap_or_else(|| DAY.default_input()),
+            method.into(),
+        ),
+    }
 }
 
-fn main() {
-    print!("Solution 1:  {}\n", solve_1());
-    print!("Solution 2: {}\n", solve_2());
+fn main() -> ExitCode {
+    day::exit(run(Cli::parse()))
 }
@@ -0,0 +1,185 @@
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+use crate::{Elf, Inventory};
+
+/// Percentiles the report lists.
+pub const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+/// Summary of the per-elf totals.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Stats {
+    pub count: usize,
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub median: f64,
+    /// Population standard deviation.
+    pub std_dev: f64,
+    /// Nearest-rank percentiles, for each of [`PERCENTILES`].
+    pub percentiles: Vec<(u8, u64)>,
+}
+
+/// Nearest-rank percentile of sorted, non-empty totals.
+fn percentile(sorted: &[u64], p: u8) -> u64 {
+    let rank = (f64::from(p) / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+impl Stats {
+    /// `None` when there are no elves.
+    pub fn new(totals: &[u64]) -> Option<Stats> {
+        let mut sorted = totals.to_vec();
+        sorted.sort_unstable();
+        let count = sorted.len();
+        let (&min, &max) = (sorted.first()?, sorted.last()?);
+
+        let mean = sorted.iter().map(|&total| total as f64).sum::<f64>() / count as f64;
+        let variance = sorted
+            .iter()
+            .map(|&total| (total as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+        let median = if count % 2 == 1 {
+            sorted[count / 2] as f64
+        } else {
+            (sorted[count / 2 - 1] as f64 + sorted[count / 2] as f64) / 2.0
+        };
+
+        Some(Stats {
+            count,
+            min,
+            max,
+            mean,
+            median,
+            std_dev: variance.sqrt(),
+            percentiles: PERCENTILES
+                .iter()
+                .map(|&p| (p, percentile(&sorted, p)))
+                .collect(),
+        })
+    }
+}
+
+/// Totals counted into `bins` equally wide ranges between the smallest and
+/// the biggest, drawn as bars at most `width` characters long.
+pub fn histogram(totals: &[u64], bins: usize, width: usize) -> String {
+    let (Some(&min), Some(&max)) = (totals.iter().min(), totals.iter().max()) else {
+        return String::new();
+    };
+    let bins = bins.max(1);
+    let bin_width = ((max - min) / bins as u64 + 1).max(1);
+    let mut counts = vec![0usize; bins];
+    for total in totals {
+        counts[(((total - min) / bin_width) as usize).min(bins - 1)] += 1;
+    }
+
+    let tallest = counts.iter().copied().max().unwrap_or(0).max(1);
+    let label_width = (min + bin_width * bins as u64).to_string().len();
+    let mut out = String::new();
+    for (idx, count) in counts.iter().enumerate() {
+        let low = min + bin_width * idx as u64;
+        let bar = "#".repeat((count * width).div_ceil(tallest));
+        out.push_str(&format!(
+            "{:>w$} - {:>w$} | {} {}\n",
+            low,
+            low + bin_width - 1,
+            bar,
+            count,
+            w = label_width
+        ));
+    }
+    out
+}
+
+/// One line of the per-elf table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Row {
+    pub elf: usize,
+    pub items: usize,
+    pub total: u64,
+    pub largest_ration: u32,
+    /// Input line of the elf's first ration.
+    pub line: usize,
+}
+
+impl From<&Elf> for Row {
+    fn from(elf: &Elf) -> Self {
+        Row {
+            elf: elf.number,
+            items: elf.items(),
+            total: elf.total(),
+            largest_ration: elf.largest_ration().map_or(0, |ration| ration.calories),
+            line: elf.rations.first().map_or(0, |ration| ration.line),
+        }
+    }
+}
+
+pub fn rows(inventory: &Inventory) -> Vec<Row> {
+    inventory.elves.iter().map(Row::from).collect()
+}
+
+pub fn write_csv(rows: &[Row], out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "elf,items,total,largest_ration,line")?;
+    for row in rows {
+        writeln!(
+            out,
+            "{},{},{},{},{}",
+            row.elf, row.items, row.total, row.largest_ration, row.line
+        )?;
+    }
+    Ok(())
+}
+
+pub fn write_json(rows: &[Row], out: &mut dyn Write) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut *out, rows)?;
+    writeln!(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../data/example");
+
+    #[test]
+    fn example_stats() {
+        let inventory = Inventory::load(EXAMPLE.as_bytes()).unwrap();
+        let stats = Stats::new(&inventory.totals()).unwrap();
+        assert_eq!((stats.count, stats.min, stats.max), (5, 4000, 24000));
+        assert_eq!((stats.mean, stats.median), (11000.0, 10000.0));
+        assert!((stats.std_dev - 6985.7).abs() < 0.1);
+        assert_eq!(
+            stats.percentiles,
+            [
+                (10, 4000),
+                (25, 6000),
+                (75, 11000),
+                (90, 24000),
+                (99, 24000)
+            ]
+        );
+        assert_eq!(Stats::new(&[]), None);
+    }
+
+    #[test]
+    fn histogram_counts_every_elf() {
+        let histogram = histogram(&[4000, 6000, 10000, 11000, 24000], 2, 10);
+        assert_eq!(
+            histogram,
+            " 4000 - 14000 | ########## 4\n14001 - 24001 | ### 1\n"
+        );
+    }
+
+    #[test]
+    fn csv_table() {
+        let inventory = Inventory::load(EXAMPLE.as_bytes()).unwrap();
+        let mut out = Vec::new();
+        write_csv(&rows(&inventory)[..2], &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "elf,items,total,largest_ration,line\n1,3,6000,3000,1\n2,1,4000,4000,5\n"
+        );
+    }
+}
@@ -0,0 +1,342 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use aoc_common::{Error, Result};
+
+use crate::{Elf, Inventory};
+
+/// Up to this many elves, [`Method::Auto`] searches for the best split.
+pub const EXACT_LIMIT: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    /// Exact for small inputs, the better heuristic otherwise.
+    Auto,
+    /// Branch and bound over every assignment.
+    Exact,
+    /// Biggest elf first, always to the lightest team.
+    Greedy,
+    /// Multiway Karmarkar-Karp: repeatedly merges the two most uneven
+    /// partial partitions, heaviest team of one with lightest of the other.
+    KarmarkarKarp,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Team {
+    /// Elf numbers, see [`Elf::number`].
+    pub elves: Vec<usize>,
+    pub total: u64,
+}
+
+impl Team {
+    fn add(&mut self, elf: usize, calories: u64) {
+        self.elves.push(elf);
+        self.total += calories;
+    }
+
+    /// Moves the shorter list of elves into the longer one, so no elf is
+    /// copied more than log n times over a whole Karmarkar-Karp run.
+    fn merge(&mut self, mut other: Team) {
+        if self.elves.len() < other.elves.len() {
+            std::mem::swap(&mut self.elves, &mut other.elves);
+        }
+        self.elves.extend(other.elves);
+        self.total += other.total;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Partition {
+    pub teams: Vec<Team>,
+}
+
+impl Partition {
+    fn new(k: usize) -> Partition {
+        Partition {
+            teams: vec![Team::default(); k],
+        }
+    }
+
+    /// Difference between the heaviest and the lightest team.
+    pub fn spread(&self) -> u64 {
+        let totals = self.teams.iter().map(|team| team.total);
+        totals.clone().max().unwrap_or(0) - totals.min().unwrap_or(0)
+    }
+
+    /// Teams heaviest first, elves in input order.
+    fn sorted(mut self) -> Partition {
+        for team in &mut self.teams {
+            team.elves.sort_unstable();
+        }
+        self.teams.sort_by_key(|team| Reverse(team.total));
+        self
+    }
+}
+
+/// Elf numbers and totals, biggest first.
+fn by_total(elves: &[Elf]) -> Vec<(usize, u64)> {
+    let mut elves: Vec<(usize, u64)> = elves.iter().map(|elf| (elf.number, elf.total())).collect();
+    elves.sort_by_key(|&(number, total)| (Reverse(total), number));
+    elves
+}
+
+fn greedy(elves: &[(usize, u64)], k: usize) -> Partition {
+    let mut partition = Partition::new(k);
+    for &(number, total) in elves {
+        let lightest = partition
+            .teams
+            .iter_mut()
+            .min_by_key(|team| team.total)
+            .expect("k is positive");
+        lightest.add(number, total);
+    }
+    partition
+}
+
+/// A partial partition in the Karmarkar-Karp heap, ordered by spread. Only
+/// the teams with elves are kept; the rest of the `k` are empty.
+struct Partial {
+    spread: u64,
+    teams: Vec<Team>,
+}
+
+impl Partial {
+    fn new(teams: Vec<Team>, k: usize) -> Partial {
+        let totals = teams.iter().map(|team| team.total);
+        let heaviest = totals.clone().max().unwrap_or(0);
+        let lightest = match teams.len() < k {
+            true => 0,
+            false => totals.min().unwrap_or(0),
+        };
+        Partial {
+            spread: heaviest - lightest,
+            teams,
+        }
+    }
+}
+
+impl PartialEq for Partial {
+    fn eq(&self, other: &Self) -> bool {
+        self.spread == other.spread
+    }
+}
+
+impl Eq for Partial {}
+
+impl PartialOrd for Partial {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Partial {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.spread.cmp(&other.spread)
+    }
+}
+
+/// Teams come out in no particular order; [`partition`] sorts them once.
+fn karmarkar_karp(elves: &[(usize, u64)], k: usize) -> Partition {
+    let mut heap: BinaryHeap<Partial> = elves
+        .iter()
+        .map(|&(number, total)| {
+            let mut team = Team::default();
+            team.add(number, total);
+            Partial::new(vec![team], k)
+        })
+        .collect();
+
+    while heap.len() > 1 {
+        let (Some(first), Some(second)) = (heap.pop(), heap.pop()) else {
+            unreachable!("the heap has two partitions");
+        };
+        let (mut first, mut second) = (first.teams, second.teams);
+        first.sort_by_key(|team| Reverse(team.total));
+        second.sort_by_key(|team| team.total);
+        // Lightest first, `second` starts after its empty teams.
+        let offset = k - second.len();
+        let mut second = second.into_iter();
+        let mut teams = Vec::with_capacity(k);
+        for (idx, mut team) in first.into_iter().enumerate() {
+            if idx >= offset {
+                team.merge(second.next().expect("k teams on each side"));
+            }
+            teams.push(team);
+        }
+        teams.extend(second);
+        heap.push(Partial::new(teams, k));
+    }
+
+    let mut teams = heap.pop().map_or_else(Vec::new, |partial| partial.teams);
+    teams.resize_with(k, Team::default);
+    Partition { teams }
+}
+
+struct Search<'a> {
+    elves: &'a [(usize, u64)],
+    /// Calories of `elves[idx..]`.
+    remaining: Vec<u64>,
+    current: Partition,
+    best: Partition,
+}
+
+impl Search<'_> {
+    fn assign(&mut self, idx: usize) {
+        let Some(&(number, calories)) = self.elves.get(idx) else {
+            if self.current.spread() < self.best.spread() {
+                self.best = self.current.clone();
+            }
+            return;
+        };
+
+        for team in 0..self.current.teams.len() {
+            let total = self.current.teams[team].total;
+            // Teams with the same total are interchangeable.
+            if self.current.teams[..team]
+                .iter()
+                .any(|other| other.total == total)
+            {
+                continue;
+            }
+
+            self.current.teams[team].add(number, calories);
+            // The heaviest team can't get lighter and the lightest can at
+            // most get everything that's left.
+            let totals = self.current.teams.iter().map(|team| team.total);
+            let heaviest = totals.clone().max().unwrap_or(0);
+            let lightest = totals.min().unwrap_or(0);
+            let bound = heaviest.saturating_sub(lightest + self.remaining[idx + 1]);
+            if bound < self.best.spread() {
+                self.assign(idx + 1);
+            }
+            let team = &mut self.current.teams[team];
+            team.elves.pop();
+            team.total -= calories;
+
+            if self.best.spread() == 0 {
+                return;
+            }
+        }
+    }
+}
+
+fn exact(elves: &[(usize, u64)], k: usize) -> Partition {
+    let mut remaining = vec![0; elves.len() + 1];
+    for idx in (0..elves.len()).rev() {
+        remaining[idx] = remaining[idx + 1] + elves[idx].1;
+    }
+    let mut search = Search {
+        elves,
+        remaining,
+        current: Partition::new(k),
+        best: karmarkar_karp(elves, k),
+    };
+    search.assign(0);
+    search.best
+}
+
+/// Splits the elves into `k` teams with totals as even as possible.
+pub fn partition(inventory: &Inventory, k: usize, method: Method) -> Result<Partition> {
+    if k == 0 {
+        return Err(Error::invalid("there has to be at least one team"));
+    }
+    let elves = by_total(&inventory.elves);
+    let partition = match method {
+        Method::Exact => exact(&elves, k),
+        Method::Greedy => greedy(&elves, k),
+        Method::KarmarkarKarp => karmarkar_karp(&elves, k),
+        Method::Auto if elves.len() <= EXACT_LIMIT => exact(&elves, k),
+        Method::Auto => {
+            let (greedy, kk) = (greedy(&elves, k), karmarkar_karp(&elves, k));
+            if greedy.spread() < kk.spread() {
+                greedy
+            } else {
+                kk
+            }
+        }
+    };
+    Ok(partition.sorted())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Rng;
+
+    use super::*;
+    use crate::Ration;
+
+    const EXAMPLE: &str = include_str!("../data/example");
+
+    fn inventory(totals: &[u32]) -> Inventory {
+        Inventory {
+            elves: totals
+                .iter()
+                .enumerate()
+                .map(|(idx, &calories)| Elf {
+                    number: idx + 1,
+                    rations: vec![Ration {
+                        calories,
+                        line: idx + 1,
+                    }],
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn example_teams() {
+        let inventory = Inventory::load(EXAMPLE.as_bytes()).unwrap();
+
+        let pair = partition(&inventory, 2, Method::Exact).unwrap();
+        assert_eq!(pair.spread(), 1000);
+        assert_eq!(pair.teams[0].elves, [2, 4]);
+
+        let three = partition(&inventory, 3, Method::Auto).unwrap();
+        assert_eq!(three.spread(), 9000);
+        assert_eq!(three.teams[0].elves, [4]);
+
+        assert!(partition(&inventory, 0, Method::Auto).is_err());
+    }
+
+    #[test]
+    fn exact_is_never_worse() {
+        let mut rng = Rng::new(1);
+        for _ in 0..50 {
+            let totals: Vec<u32> = (0..rng.between(1, 10))
+                .map(|_| rng.between(1, 100) as u32)
+                .collect();
+            let inventory = inventory(&totals);
+            let k = rng.between(1, 4) as usize;
+
+            let best = partition(&inventory, k, Method::Exact).unwrap();
+            for method in [Method::Greedy, Method::KarmarkarKarp] {
+                let heuristic = partition(&inventory, k, method).unwrap();
+                assert!(best.spread() <= heuristic.spread(), "{:?}", totals);
+            }
+
+            let mut elves: Vec<usize> = best.teams.iter().flat_map(|t| t.elves.clone()).collect();
+            elves.sort_unstable();
+            assert_eq!(elves, (1..=totals.len()).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn heuristics_scale() {
+        // Quadratic merging takes minutes here, even in release.
+        let mut rng = Rng::new(2);
+        let totals: Vec<u32> = (0..100_000)
+            .map(|_| rng.between(1, 100_000) as u32)
+            .collect();
+        let inventory = inventory(&totals);
+        for k in [3, 100] {
+            let teams = partition(&inventory, k, Method::Auto).unwrap();
+            assert_eq!(teams.teams.len(), k);
+            assert!(teams
+                .teams
+                .windows(2)
+                .all(|pair| pair[0].total >= pair[1].total));
+            assert!(teams.teams.iter().all(|team| team.elves.is_sorted()));
+            let elves: usize = teams.teams.iter().map(|team| team.elves.len()).sum();
+            assert_eq!(elves, totals.len());
+        }
+    }
+}
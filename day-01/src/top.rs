@@ -0,0 +1,129 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    io::{BufRead, BufReader, Read},
+};
+
+use aoc_common::{Diagnostic, Error, Result};
+
+/// The `k` biggest totals pushed so far, in O(k) memory.
+#[derive(Debug, Clone)]
+pub struct TopK {
+    k: usize,
+    heap: BinaryHeap<Reverse<u64>>,
+}
+
+impl TopK {
+    /// Room for up to 1024 totals is reserved up front; a bigger `k` grows
+    /// the heap as totals arrive.
+    pub fn new(k: usize) -> TopK {
+        TopK {
+            k,
+            heap: BinaryHeap::with_capacity(k.saturating_add(1).min(1024)),
+        }
+    }
+
+    pub fn push(&mut self, total: u64) {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(total));
+        } else if self.heap.peek().is_some_and(|smallest| total > smallest.0) {
+            self.heap.pop();
+            self.heap.push(Reverse(total));
+        }
+    }
+
+    /// Sum of the kept totals, `None` if it doesn't fit a u64.
+    pub fn sum(&self) -> Option<u64> {
+        self.heap
+            .iter()
+            .try_fold(0u64, |sum, total| sum.checked_add(total.0))
+    }
+
+    /// The kept totals, biggest first.
+    pub fn into_sorted(self) -> Vec<u64> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|total| total.0)
+            .collect()
+    }
+}
+
+impl Extend<u64> for TopK {
+    fn extend<I: IntoIterator<Item = u64>>(&mut self, totals: I) {
+        for total in totals {
+            self.push(total);
+        }
+    }
+}
+
+/// Reads elves straight from `reader`, one line at a time, and keeps the `k`
+/// biggest totals. Nothing but the current line and the heap is held in
+/// memory, so the input can be any size.
+pub fn stream_top_k<R: Read>(reader: R, k: usize) -> Result<TopK> {
+    let mut reader = BufReader::new(reader);
+    let mut top = TopK::new(k);
+    let mut line = String::new();
+    let mut number = 0;
+    let mut elf: Option<u64> = None;
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        number += 1;
+
+        let text = line.trim_end_matches(['\n', '\r']);
+        if text.trim().is_empty() {
+            top.extend(elf.take());
+            continue;
+        }
+        let calories: u64 = text
+            .trim()
+            .parse()
+            .map_err(|_| Diagnostic::unexpected(text, text.trim(), "calories").at_line(number))?;
+        let total = elf.unwrap_or(0).checked_add(calories).ok_or_else(|| {
+            Error::from(Diagnostic::token(text, text, "the elf's total overflows").at_line(number))
+        })?;
+        elf = Some(total);
+    }
+    top.extend(elf);
+
+    Ok(top)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../data/example");
+
+    #[test]
+    fn keeps_the_biggest_totals() {
+        let top = stream_top_k(EXAMPLE.as_bytes(), 3).unwrap();
+        assert_eq!(top.sum(), Some(45000));
+        assert_eq!(top.into_sorted(), [24000, 11000, 10000]);
+
+        let top = stream_top_k(EXAMPLE.as_bytes(), usize::MAX).unwrap();
+        assert_eq!(top.into_sorted().len(), 5);
+
+        let top = stream_top_k(EXAMPLE.trim_end().as_bytes(), 10).unwrap();
+        assert_eq!(top.into_sorted(), [24000, 11000, 10000, 6000, 4000]);
+
+        assert!(stream_top_k(EXAMPLE.as_bytes(), 0)
+            .unwrap()
+            .into_sorted()
+            .is_empty());
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let input = format!("{}\n1\n", u64::MAX);
+        assert!(stream_top_k(input.as_bytes(), 1).is_err());
+
+        let mut top = TopK::new(2);
+        top.extend([u64::MAX, 1]);
+        assert_eq!(top.sum(), None);
+    }
+}
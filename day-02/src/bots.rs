@@ -0,0 +1,319 @@
+use aoc_common::{Error, Result, Rng};
+
+use crate::{
+    rps::Mechanic,
+    tournament::{Bot, Player},
+    Game, Round, Rules, Shape,
+};
+
+/// Names [`build`] understands.
+pub const BOTS: [&str; 6] = [
+    "constant",
+    "cycle",
+    "random",
+    "frequency",
+    "markov",
+    "guide",
+];
+
+/// Always the same shape.
+pub struct Constant<'r> {
+    shape: Shape<'r>,
+}
+
+impl<'r> Constant<'r> {
+    pub fn new(shape: Shape<'r>) -> Self {
+        Constant { shape }
+    }
+}
+
+impl<'r> Player<'r> for Constant<'r> {
+    fn name(&self) -> String {
+        format!("constant:{}", self.shape)
+    }
+
+    fn play(&mut self) -> Shape<'r> {
+        self.shape
+    }
+}
+
+/// Every shape in turn, in rules order.
+pub struct Cycle<'r> {
+    rules: &'r Rules,
+    next: usize,
+}
+
+impl<'r> Cycle<'r> {
+    pub fn new(rules: &'r Rules) -> Self {
+        Cycle { rules, next: 0 }
+    }
+}
+
+impl<'r> Player<'r> for Cycle<'r> {
+    fn name(&self) -> String {
+        "cycle".to_string()
+    }
+
+    fn play(&mut self) -> Shape<'r> {
+        let shape = self
+            .rules
+            .shapes()
+            .nth(self.next)
+            .expect("next is in range");
+        self.next = (self.next + 1) % self.rules.len();
+        shape
+    }
+
+    fn reset(&mut self) {
+        self.next = 0;
+    }
+}
+
+/// Uniformly random shapes, the same ones every match.
+pub struct Random<'r> {
+    rules: &'r Rules,
+    seed: u64,
+    rng: Rng,
+}
+
+impl<'r> Random<'r> {
+    pub fn new(rules: &'r Rules, seed: u64) -> Self {
+        Random {
+            rules,
+            seed,
+            rng: Rng::new(seed),
+        }
+    }
+}
+
+impl<'r> Player<'r> for Random<'r> {
+    fn name(&self) -> String {
+        format!("random:{}", self.seed)
+    }
+
+    fn play(&mut self) -> Shape<'r> {
+        let idx = self.rng.index(self.rules.len());
+        self.rules.shapes().nth(idx).expect("the index is in range")
+    }
+
+    fn reset(&mut self) {
+        self.rng = Rng::new(self.seed);
+    }
+}
+
+/// The most common of `counts`; the first one on a tie.
+fn most_common<'r>(rules: &'r Rules, counts: &[u32]) -> Shape<'r> {
+    rules
+        .shapes()
+        .rev()
+        .max_by_key(|shape| counts[shape.index()])
+        .expect("rules have shapes")
+}
+
+/// Beats whatever the opponent played most so far.
+pub struct Frequency<'r> {
+    rules: &'r Rules,
+    counts: Vec<u32>,
+}
+
+impl<'r> Frequency<'r> {
+    pub fn new(rules: &'r Rules) -> Self {
+        Frequency {
+            rules,
+            counts: vec![0; rules.len()],
+        }
+    }
+}
+
+impl<'r> Player<'r> for Frequency<'r> {
+    fn name(&self) -> String {
+        "frequency".to_string()
+    }
+
+    fn play(&mut self) -> Shape<'r> {
+        most_common(self.rules, &self.counts).loses_to()
+    }
+
+    fn observe(&mut self, opponent: Shape<'r>) {
+        self.counts[opponent.index()] += 1;
+    }
+
+    fn reset(&mut self) {
+        self.counts.fill(0);
+    }
+}
+
+/// Predicts the opponent's next shape from what usually followed their last
+/// one, and beats it. Falls back to [`Frequency`] until it has seen a
+/// transition.
+pub struct Markov<'r> {
+    rules: &'r Rules,
+    last: Option<Shape<'r>>,
+    /// `transitions[a][b]` counts `b` right after `a`.
+    transitions: Vec<Vec<u32>>,
+    counts: Vec<u32>,
+}
+
+impl<'r> Markov<'r> {
+    pub fn new(rules: &'r Rules) -> Self {
+        Markov {
+            rules,
+            last: None,
+            transitions: vec![vec![0; rules.len()]; rules.len()],
+            counts: vec![0; rules.len()],
+        }
+    }
+}
+
+impl<'r> Player<'r> for Markov<'r> {
+    fn name(&self) -> String {
+        "markov".to_string()
+    }
+
+    fn play(&mut self) -> Shape<'r> {
+        let seen = self
+            .last
+            .map(|last| &self.transitions[last.index()])
+            .filter(|row| row.iter().any(|&count| count > 0));
+        most_common(self.rules, seen.unwrap_or(&self.counts)).loses_to()
+    }
+
+    fn observe(&mut self, opponent: Shape<'r>) {
+        if let Some(last) = self.last {
+            self.transitions[last.index()][opponent.index()] += 1;
+        }
+        self.counts[opponent.index()] += 1;
+        self.last = Some(opponent);
+    }
+
+    fn reset(&mut self) {
+        *self = Markov::new(self.rules);
+    }
+}
+
+/// Our moves from the strategy guide, read like part one, over and over.
+pub struct Replay<'r> {
+    moves: Vec<Shape<'r>>,
+    next: usize,
+}
+
+impl<'r> Replay<'r> {
+    pub fn new(rules: &'r Rules, guide: &[String]) -> Result<Self> {
+        let moves = guide
+            .iter()
+            .enumerate()
+            .map(|(idx, line)| {
+                Game::read(rules, line)
+                    .map(|game| game.player())
+                    .map_err(|err| Error::from(err.at_line(idx + 1)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if moves.is_empty() {
+            return Err(Error::invalid("the guide has no moves to replay"));
+        }
+        Ok(Replay { moves, next: 0 })
+    }
+}
+
+impl<'r> Player<'r> for Replay<'r> {
+    fn name(&self) -> String {
+        "guide".to_string()
+    }
+
+    fn play(&mut self) -> Shape<'r> {
+        let shape = self.moves[self.next];
+        self.next = (self.next + 1) % self.moves.len();
+        shape
+    }
+
+    fn reset(&mut self) {
+        self.next = 0;
+    }
+}
+
+/// A bot from its name, one of [`BOTS`]. `constant:Paper` picks the shape,
+/// first in the rules by default, and `random:7` the seed.
+pub fn build<'r>(spec: &str, rules: &'r Rules, guide: &[String], seed: u64) -> Result<Bot<'r>> {
+    let (name, arg) = match spec.split_once(':') {
+        Some((name, arg)) => (name, Some(arg)),
+        None => (spec, None),
+    };
+    let bot: Bot = match name {
+        "constant" => {
+            let shape = match arg {
+                Some(arg) => rules
+                    .shape(arg)
+                    .ok_or_else(|| Error::invalid(format!("no shape {:?}", arg)))?,
+                None => rules.shapes().next().expect("rules have shapes"),
+            };
+            Box::new(Constant::new(shape))
+        }
+        "random" => {
+            let seed = match arg {
+                Some(arg) => arg
+                    .parse()
+                    .map_err(|_| Error::invalid(format!("bad seed {:?}", arg)))?,
+                None => seed,
+            };
+            Box::new(Random::new(rules, seed))
+        }
+        "cycle" | "frequency" | "markov" | "guide" if arg.is_some() => {
+            return Err(Error::invalid(format!("{} takes no argument", name)))
+        }
+        "cycle" => Box::new(Cycle::new(rules)),
+        "frequency" => Box::new(Frequency::new(rules)),
+        "markov" => Box::new(Markov::new(rules)),
+        "guide" => Box::new(Replay::new(rules, guide)?),
+        _ => {
+            return Err(Error::invalid(format!(
+                "no bot {:?}, try {}",
+                name,
+                BOTS.join(", ")
+            )))
+        }
+    };
+    Ok(bot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tournament::play_match;
+
+    #[test]
+    fn markov_learns_a_cycle() {
+        let rules = Rules::classic();
+        let mut markov = Markov::new(rules);
+        let mut cycle = Cycle::new(rules);
+        let (markov_points, cycle_points) = play_match(&mut markov, &mut cycle, 300);
+        // Once it has seen each transition, every round is a win.
+        assert!(markov_points > 2 * cycle_points);
+    }
+
+    #[test]
+    fn build_by_name() {
+        let rules = Rules::classic();
+        let guide = vec!["A Y".to_string()];
+        for spec in BOTS {
+            assert_eq!(
+                build(spec, rules, &guide, 1)
+                    .unwrap()
+                    .name()
+                    .split(':')
+                    .next(),
+                Some(spec)
+            );
+        }
+        assert_eq!(
+            build("constant:Paper", rules, &guide, 1)
+                .unwrap()
+                .play()
+                .name(),
+            "Paper"
+        );
+        assert!(build("constant:Lizard", rules, &guide, 1).is_err());
+        assert!(build("cycle:2", rules, &guide, 1).is_err());
+        assert!(build("clever", rules, &guide, 1).is_err());
+        assert!(build("guide", rules, &[], 1).is_err());
+    }
+}
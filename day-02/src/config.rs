@@ -0,0 +1,161 @@
+use std::path::Path;
+
+use aoc_common::{input, Error, Result};
+use serde::Deserialize;
+
+use crate::{Outcome, OutcomeRule, Rules, ShapeRule};
+
+/// A rules file. Every field is optional and overrides the classic rules:
+///
+/// ```toml
+/// [[shape]]
+/// name = "Rock"
+/// score = 10
+/// player = "R"
+///
+/// [[shape]]           # not a classic shape, so everything is needed
+/// name = "Lizard"
+/// score = 4
+/// opponent = "D"
+/// player = "L"
+/// beats = ["Paper", "Spock"]
+///
+/// [outcome.win]
+/// score = 12
+/// ```
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RulesFile {
+    #[serde(default)]
+    shape: Vec<ShapeEntry>,
+    #[serde(default)]
+    outcome: OutcomeEntries,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ShapeEntry {
+    name: String,
+    score: Option<u32>,
+    opponent: Option<String>,
+    player: Option<String>,
+    beats: Option<Vec<String>>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OutcomeEntries {
+    loss: Option<OutcomeEntry>,
+    draw: Option<OutcomeEntry>,
+    win: Option<OutcomeEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OutcomeEntry {
+    score: Option<u32>,
+    code: Option<String>,
+}
+
+impl ShapeEntry {
+    fn apply(self, shapes: &mut Vec<ShapeRule>) -> Result<()> {
+        if let Some(shape) = shapes.iter_mut().find(|shape| shape.name == self.name) {
+            shape.score = self.score.unwrap_or(shape.score);
+            shape.opponent_code = self.opponent.unwrap_or(shape.opponent_code.clone());
+            shape.player_code = self.player.unwrap_or(shape.player_code.clone());
+            shape.beats = self.beats.unwrap_or(shape.beats.clone());
+            return Ok(());
+        }
+
+        let missing =
+            |field: &str| Error::invalid(format!("new shape {} needs a {}", self.name, field));
+        shapes.push(ShapeRule {
+            score: self.score.ok_or_else(|| missing("score"))?,
+            opponent_code: self
+                .opponent
+                .clone()
+                .ok_or_else(|| missing("opponent code"))?,
+            player_code: self.player.clone().ok_or_else(|| missing("player code"))?,
+            beats: self
+                .beats
+                .clone()
+                .ok_or_else(|| missing("list of shapes it beats"))?,
+            name: self.name,
+        });
+        Ok(())
+    }
+}
+
+impl Rules {
+    /// The classic rules with the overrides of a rules file.
+    pub fn from_toml(text: &str) -> Result<Rules> {
+        let file: RulesFile =
+            toml::from_str(text).map_err(|err| Error::invalid(err.to_string()))?;
+        let classic = Rules::classic();
+
+        let mut shapes: Vec<ShapeRule> =
+            classic.shapes().map(|shape| shape.rule().clone()).collect();
+        for entry in file.shape {
+            entry.apply(&mut shapes)?;
+        }
+
+        let entries = [file.outcome.loss, file.outcome.draw, file.outcome.win];
+        let outcomes = Outcome::ALL.map(|outcome| {
+            let rule = classic.outcome_rule(outcome);
+            match &entries[outcome as usize] {
+                Some(entry) => OutcomeRule {
+                    score: entry.score.unwrap_or(rule.score),
+                    code: entry.code.clone().unwrap_or(rule.code.clone()),
+                },
+                None => rule.clone(),
+            }
+        });
+
+        Rules::new(shapes, outcomes)
+    }
+
+    pub fn load(path: &Path) -> Result<Rules> {
+        let text = input::read_string(input::open(path)?)?;
+        Rules::from_toml(&text)
+            .map_err(|err| Error::invalid(format!("{}: {}", path.display(), err)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIZARD_SPOCK: &str = include_str!("../data/lizard-spock.toml");
+
+    #[test]
+    fn empty_file_is_classic() {
+        assert_eq!(&Rules::from_toml("").unwrap(), Rules::classic());
+    }
+
+    #[test]
+    fn overrides_scores_and_codes() {
+        let rules = Rules::from_toml(
+            "[[shape]]\nname = \"Rock\"\nscore = 10\nplayer = \"R\"\n\n[outcome.win]\nscore = 12\n",
+        )
+        .unwrap();
+        let rock = rules.player_move("R").unwrap();
+        assert_eq!((rock.name(), rock.score()), ("Rock", 10));
+        assert_eq!(rules.outcome_rule(Outcome::Win).score, 12);
+        assert_eq!(rules.outcome_rule(Outcome::Win).code, "Z");
+    }
+
+    #[test]
+    fn lizard_spock_file() {
+        let rules = Rules::from_toml(LIZARD_SPOCK).unwrap();
+        assert_eq!(rules.len(), 5);
+        let spock = rules.opponent_move("E").unwrap();
+        assert!(spock.beats(&rules.shape("Rock").unwrap()));
+    }
+
+    #[test]
+    fn bad_files_are_rejected() {
+        assert!(Rules::from_toml("[[shape]]\nname = \"Rock\"\nscroe = 1\n").is_err());
+        assert!(Rules::from_toml("[[shape]]\nname = \"Lizard\"\nscore = 4\n").is_err());
+        assert!(Rules::from_toml("[outcome.win]\ncode = \"X\"\n").is_err());
+    }
+}
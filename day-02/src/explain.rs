@@ -0,0 +1,115 @@
+use std::io::{self, Write};
+
+use aoc_common::{Diagnostic, Part, Result};
+
+use crate::{FixedGame, Game, Outcome, Round, Rules};
+
+/// How one line of the guide scores.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub part: Part,
+    pub line: usize,
+    pub opponent: String,
+    pub player: String,
+    pub outcome: Outcome,
+    pub shape_points: u32,
+    pub outcome_points: u32,
+    /// Score of the guide up to and including this line.
+    pub total: u32,
+}
+
+fn rows<'r, M: Round<'r>>(rules: &'r Rules, guide: &[String], part: Part) -> Result<Vec<Row>> {
+    let mut total = 0;
+    guide
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| {
+            let round = M::read(rules, line).map_err(|err: Diagnostic| err.at_line(idx + 1))?;
+            let game = round.game();
+            let shape_points = game.player().score();
+            let outcome_points = rules.outcome_rule(game.outcome()).score;
+            total += round.result();
+            Ok(Row {
+                part,
+                line: idx + 1,
+                opponent: game.opponent().name().to_string(),
+                player: game.player().name().to_string(),
+                outcome: game.outcome(),
+                shape_points,
+                outcome_points,
+                total,
+            })
+        })
+        .collect()
+}
+
+/// Every round of the guide as `part` reads it: part one as moves, part two
+/// as outcomes.
+pub fn explain(rules: &Rules, guide: &[String], part: Part) -> Result<Vec<Row>> {
+    match part {
+        Part::One => rows::<Game>(rules, guide, part),
+        Part::Two => rows::<FixedGame>(rules, guide, part),
+    }
+}
+
+pub fn write_csv(rows: &[Row], out: &mut dyn Write) -> io::Result<()> {
+    writeln!(
+        out,
+        "part,line,opponent,player,outcome,shape_points,outcome_points,total"
+    )?;
+    for row in rows {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{}",
+            row.part,
+            row.line,
+            row.opponent,
+            row.player,
+            row.outcome,
+            row.shape_points,
+            row.outcome_points,
+            row.total
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::input;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../data/example");
+
+    #[test]
+    fn example_rounds() {
+        let guide = input::lines(EXAMPLE.as_bytes()).unwrap();
+        let two = explain(Rules::classic(), &guide, Part::Two).unwrap();
+        assert_eq!(
+            two[1],
+            Row {
+                part: Part::Two,
+                line: 2,
+                opponent: "Paper".to_string(),
+                player: "Rock".to_string(),
+                outcome: Outcome::Loss,
+                shape_points: 1,
+                outcome_points: 0,
+                total: 5,
+            }
+        );
+        assert_eq!(two.last().map(|row| row.total), Some(12));
+
+        let mut out = Vec::new();
+        write_csv(
+            &explain(Rules::classic(), &guide, Part::One).unwrap(),
+            &mut out,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap().lines().nth(1),
+            Some("1,1,Rock,Paper,Win,2,6,8")
+        );
+    }
+}
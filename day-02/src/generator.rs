@@ -0,0 +1,17 @@
+use std::io::{self, Write};
+
+use aoc_common::{Generator, Rng};
+
+use crate::RockPaperScissors;
+
+/// `size` is the number of rounds.
+impl Generator for RockPaperScissors {
+    fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+        for _ in 0..size {
+            let opponent = rng.pick(&["A", "B", "C"]);
+            let response = rng.pick(&["X", "Y", "Z"]);
+            writeln!(out, "{} {}", opponent, response)?;
+        }
+        Ok(())
+    }
+}
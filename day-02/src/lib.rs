@@ -0,0 +1,120 @@
+use std::io::Read;
+
+use aoc_common::{input, Day, Diagnostic, Result, Solution};
+
+pub mod bots;
+mod config;
+pub mod explain;
+mod generator;
+pub mod mappings;
+pub mod play;
+mod rps;
+mod rules;
+pub mod strategy;
+pub mod synth;
+pub mod tournament;
+pub use rps::{FixedGame, Game, Match, Outcome, Round};
+pub use rules::{OutcomeRule, Rules, Shape, ShapeRule, MAX_SHAPES};
+
+pub const DAY: Day =
+    Day::new::<RockPaperScissors>(env!("CARGO_MANIFEST_DIR")).with_generator::<RockPaperScissors>();
+
+pub struct RockPaperScissors;
+
+/// The strategy guide read both ways with the classic rules: as the shapes
+/// to play, and as the results to get.
+pub struct Guide {
+    games: Vec<Game<'static>>,
+    fixed: Vec<FixedGame<'static>>,
+}
+
+fn load_strategies<R: Read>(reader: R) -> Result<Guide> {
+    let strategies = input::lines(reader)?;
+    Ok(Guide {
+        games: read(Rules::classic(), &strategies)?,
+        fixed: read(Rules::classic(), &strategies)?,
+    })
+}
+
+/// Every line of the guide, read with `rules`.
+pub fn read<'r, M: Round<'r>>(rules: &'r Rules, strategies: &[String]) -> Result<Vec<M>> {
+    strategies
+        .iter()
+        .enumerate()
+        .map(|(idx, strategy)| {
+            M::read(rules, strategy).map_err(|err: Diagnostic| err.at_line(idx + 1).into())
+        })
+        .collect()
+}
+
+fn score(rounds: &[impl Match]) -> u32 {
+    rounds.iter().map(Match::result).sum()
+}
+
+/// Total score of the guide, read with `rules`.
+pub fn solve<'r, M: Round<'r>>(rules: &'r Rules, strategies: &[String]) -> Result<u32> {
+    Ok(score(&read::<M>(rules, strategies)?))
+}
+
+impl Solution for RockPaperScissors {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Rock Paper Scissors";
+
+    type Input = Guide;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse<R: Read>(reader: R) -> Result<Self::Input> {
+        load_strategies(reader)
+    }
+
+    fn part_one(guide: &Self::Input) -> Result<u32> {
+        Ok(score(&guide.games))
+    }
+
+    fn part_two(guide: &Self::Input) -> Result<u32> {
+        Ok(score(&guide.fixed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Error;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../data/example");
+
+    #[test]
+    fn part_one_example() {
+        let input = RockPaperScissors::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(RockPaperScissors::part_one(&input).unwrap(), 15);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = RockPaperScissors::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(RockPaperScissors::part_two(&input).unwrap(), 12);
+    }
+
+    #[test]
+    fn unknown_move_is_reported() {
+        let Err(Error::Parse(err)) = RockPaperScissors::parse("A Y\nB Q\n".as_bytes()) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.line, err.column(), err.text()), (2, 3, "Q"));
+        assert_eq!(err.expected.as_deref(), Some("your move, X, Y or Z"));
+    }
+
+    #[test]
+    fn lizard_spock() {
+        let rules = Rules::lizard_spock();
+        let guide: Vec<String> = ["E V", "D Z", "A Y"].map(String::from).into();
+        // Rock loses to Spock, Spock to Lizard, Lizard to Rock.
+        assert_eq!(solve::<Game>(&rules, &guide).unwrap(), 1 + 5 + 4);
+        // The best scoring answer is picked: draw with Spock, lose to Lizard
+        // with Spock rather than Paper, beat Rock with Spock.
+        let guide: Vec<String> = ["E Y", "D X", "A Z"].map(String::from).into();
+        assert_eq!(solve::<FixedGame>(&rules, &guide).unwrap(), 8 + 5 + 11);
+    }
+}
@@ -1,39 +1,494 @@
 use std::{
     fs::File,
-    io::{BufRead, BufReader},
-    path::Path,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
 };
 
-mod rps;
-use log::info;
-use rps::{FixedGame, Game, Match};
+use aoc_common::{
+    day::{self, print_answer},
+    input, Error, Part, Result,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use day_02::{
+    bots,
+    explain::{self, Row},
+    mappings, play, solve, strategy, synth,
+    tournament::{self, Bot, MatchResult},
+    FixedGame, Game, Rules, DAY,
+};
+
+#[derive(Parser)]
+#[command(name = "day-02", about = "Rock Paper Scissors")]
+#[command(args_conflicts_with_subcommands = true)]
+struct Cli {
+    /// Puzzle input, `-` for stdin; defaults to data/input
+    input: Option<PathBuf>,
+    /// TOML file overriding the shapes, scores and letter codes
+    #[arg(short, long, global = true)]
+    rules: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Score the guide with every reading of our column, best first
+    Mappings {
+        /// Puzzle input, `-` for stdin; defaults to data/input
+        input: Option<PathBuf>,
+        /// Only list the readings that score exactly this
+        #[arg(short, long)]
+        target: Option<u32>,
+    },
+    /// Show how every round of the guide scores
+    Explain {
+        /// Puzzle input, `-` for stdin; defaults to data/input
+        input: Option<PathBuf>,
+        /// Part to explain, both when omitted
+        #[arg(short, long)]
+        part: Option<u8>,
+        /// Write the rounds as CSV, instead of the table unless --output is given
+        #[arg(long)]
+        csv: bool,
+        /// Where to write the CSV
+        #[arg(short, long, requires = "csv")]
+        output: Option<PathBuf>,
+    },
+    /// Play bots against each other and rank them
+    Tournament {
+        /// Strategy guide for the `guide` bot; defaults to data/input
+        input: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Bracket::RoundRobin)]
+        bracket: Bracket,
+        /// Rounds per match
+        #[arg(short = 'n', long, default_value_t = 1000)]
+        rounds: usize,
+        /// Seed for the random bots
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Lineup in seeding order, e.g. `constant:Paper,random:7,markov`
+        #[arg(long, value_delimiter = ',', default_values_t = bots::BOTS.map(String::from))]
+        bots: Vec<String>,
+    },
+    /// Best responses to the opponent's column and the game's equilibrium
+    Strategy {
+        /// Puzzle input, `-` for stdin; defaults to data/input
+        input: Option<PathBuf>,
+        /// Also list the best response for every round
+        #[arg(long)]
+        per_round: bool,
+    },
+    /// Play against a bot, one move per line on stdin
+    Play {
+        /// The opponent, one of the tournament bots
+        #[arg(long, default_value = "markov")]
+        bot: String,
+        /// Seed for a random bot
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Strategy guide for the `guide` bot; defaults to data/input
+        #[arg(long)]
+        guide: Option<PathBuf>,
+        /// Save the rounds as a strategy guide, the bot's moves first
+        #[arg(short, long)]
+        transcript: Option<PathBuf>,
+    },
+    /// Write a guide that scores exactly the target
+    Synth {
+        /// Total the guide has to score
+        #[arg(long)]
+        target: u64,
+        /// Number of rounds; defaults to the length of --opponents
+        #[arg(short = 'n', long, required_unless_present = "opponents")]
+        rounds: Option<usize>,
+        /// Part whose reading is scored
+        #[arg(short, long, default_value_t = 1)]
+        part: u8,
+        /// Guide whose opponent column has to be kept
+        #[arg(long)]
+        opponents: Option<PathBuf>,
+        /// Where to write the guide; defaults to stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Bracket {
+    RoundRobin,
+    Elimination,
+}
+
+fn load_rules(rules: Option<PathBuf>) -> Result<Rules> {
+    match rules {
+        Some(rules) => Rules::load(&rules),
+        None => Ok(Rules::classic().clone()),
+    }
+}
+
+fn load(path: &Path, rules: Option<PathBuf>) -> Result<(Rules, Vec<String>)> {
+    let rules = load_rules(rules)?;
+    let guide = input::lines(input::open(path)?).map_err(|err| err.in_file(path))?;
+    Ok((rules, guide))
+}
+
+fn run_with_rules(path: PathBuf, rules: PathBuf) -> Result<()> {
+    let (rules, guide) = load(&path, Some(rules))?;
+    let one = solve::<Game>(&rules, &guide).map_err(|err| err.in_file(&path))?;
+    let two = solve::<FixedGame>(&rules, &guide).map_err(|err| err.in_file(&path))?;
+    println!("Day {:02} part {}: {}", DAY.number, Part::One, one);
+    println!("Day {:02} part {}: {}", DAY.number, Part::Two, two);
+    Ok(())
+}
+
+fn run_mappings(path: PathBuf, rules: Option<PathBuf>, target: Option<u32>) -> Result<()> {
+    let (rules, guide) = load(&path, rules)?;
+    let all = mappings::mappings(&rules, &guide).map_err(|err| err.in_file(&path))?;
+    let shown: Vec<(usize, &mappings::Mapping)> = all
+        .iter()
+        .enumerate()
+        .filter(|(_, mapping)| target.is_none_or(|target| mapping.total == target))
+        .collect();
+
+    if let (Some(target), true) = (target, shown.is_empty()) {
+        println!("no reading of the guide scores {}", target);
+        return Ok(());
+    }
+    println!("rank  reading   total  mapping");
+    for (idx, mapping) in shown {
+        println!(
+            "{:>4}  {:<8} {:>6}  {}",
+            idx + 1,
+            mapping.reading,
+            mapping.total,
+            mapping
+        );
+    }
+    Ok(())
+}
 
-static STRATEGY_FILE: &'static str = "./data/1.txt";
+fn print_rows(rows: &[Row]) {
+    println!("part  line  opponent  player    outcome  shape  outcome  total");
+    for row in rows {
+        println!(
+            "{:>4}  {:>4}  {:<8}  {:<8}  {:<7}  {:>5}  {:>7}  {:>5}",
+            row.part as u8,
+            row.line,
+            row.opponent,
+            row.player,
+            row.outcome,
+            row.shape_points,
+            row.outcome_points,
+            row.total
+        );
+    }
+}
 
-fn load_strategies(path: &str) -> Vec<String> {
-    let file_path = Path::new(path);
-    let file = File::open(file_path).expect("Couldn't open file");
+fn run_explain(
+    path: PathBuf,
+    rules: Option<PathBuf>,
+    part: Option<u8>,
+    csv: bool,
+    output: Option<PathBuf>,
+) -> Result<()> {
+    let (rules, guide) = load(&path, rules)?;
+    let parts = match part {
+        Some(part) => vec![Part::try_from(part)?],
+        None => Part::ALL.to_vec(),
+    };
+    let mut rows = Vec::new();
+    for part in parts {
+        rows.extend(explain::explain(&rules, &guide, part).map_err(|err| err.in_file(&path))?);
+    }
 
-    let buf = BufReader::new(file);
-    buf.lines().map(|l| l.unwrap()).collect()
+    if csv {
+        let mut out: BufWriter<Box<dyn Write>> = BufWriter::new(match &output {
+            Some(path) => Box::new(File::create(path)?),
+            None => Box::new(io::stdout().lock()),
+        });
+        explain::write_csv(&rows, &mut out)?;
+        out.flush()?;
+        if output.is_none() {
+            return Ok(());
+        }
+    }
+    print_rows(&rows);
+    Ok(())
+}
+
+fn print_match(players: &[Bot], result: &MatchResult) {
+    println!(
+        "  {} {} - {} {}",
+        players[result.first].name(),
+        result.scores.0,
+        result.scores.1,
+        players[result.second].name()
+    );
+}
+
+fn run_tournament(
+    path: PathBuf,
+    rules: Option<PathBuf>,
+    bracket: Bracket,
+    rounds: usize,
+    seed: u64,
+    lineup: &[String],
+) -> Result<()> {
+    let (rules, guide) = load(&path, rules)?;
+    let mut players = lineup
+        .iter()
+        .map(|spec| bots::build(spec, &rules, &guide, seed))
+        .collect::<Result<Vec<Bot>>>()
+        .map_err(|err| err.in_file(&path))?;
+
+    let matches = match bracket {
+        Bracket::RoundRobin => tournament::round_robin(&mut players, rounds),
+        Bracket::Elimination => {
+            let bracket = tournament::elimination(&mut players, rounds);
+            for (idx, stage) in bracket.stages.iter().enumerate() {
+                println!("stage {}:", idx + 1);
+                for result in stage {
+                    print_match(&players, result);
+                }
+            }
+            if let Some(champion) = bracket.champion {
+                println!("champion: {}", players[champion].name());
+            }
+            println!();
+            bracket.stages.concat()
+        }
+    };
+
+    let standings = tournament::leaderboard(&players, &matches);
+    let width = standings
+        .iter()
+        .map(|standing| standing.name.len())
+        .max()
+        .unwrap_or(0);
+    println!("rank  {:<w$}    points      W    D    L", "bot", w = width);
+    for (idx, standing) in standings.iter().enumerate() {
+        println!(
+            "{:>4}  {:<w$}  {:>8}  {:>5}  {:>3}  {:>3}",
+            idx + 1,
+            standing.name,
+            standing.points,
+            standing.wins,
+            standing.draws,
+            standing.losses,
+            w = width
+        );
+    }
+    Ok(())
+}
+
+fn run_strategy(path: PathBuf, rules: Option<PathBuf>, per_round: bool) -> Result<()> {
+    let (rules, guide) = load(&path, rules)?;
+    let analysis = strategy::analyse(&rules, &guide).map_err(|err| err.in_file(&path))?;
+
+    if per_round {
+        println!("line  opponent  best      score");
+        for (idx, (opponent, (response, score))) in strategy::opponent_column(&rules, &guide)?
+            .iter()
+            .zip(&analysis.responses)
+            .enumerate()
+        {
+            println!(
+                "{:>4}  {:<8}  {:<8}  {:>5}",
+                idx + 1,
+                opponent,
+                response,
+                score
+            );
+        }
+        println!();
+    }
+
+    let width = rules
+        .shapes()
+        .map(|shape| shape.name().len())
+        .max()
+        .unwrap_or(0);
+    println!("rounds: {}", analysis.rounds);
+    println!("best response every round: {}", analysis.best_total);
+    let (shape, total) = analysis.best_constant;
+    println!("best single shape: {} for {}", shape, total);
+    println!();
+    println!(
+        "{:<w$}  opponent  expected  equilibrium",
+        "shape",
+        w = width
+    );
+    for shape in rules.shapes() {
+        let idx = shape.index();
+        let mix = analysis
+            .equilibrium
+            .as_ref()
+            .map_or(String::from("-"), |mix| format!("{:.4}", mix[idx]));
+        println!(
+            "{:<w$}  {:>7.1}%  {:>8.3}  {:>11}",
+            shape.name(),
+            analysis.distribution[idx] * 100.0,
+            analysis.expected[idx],
+            mix,
+            w = width
+        );
+    }
+    match analysis.equilibrium_expected() {
+        Some(expected) => println!("{:<w$}  {:>8}  {:>8.3}", "mix", "", expected, w = width),
+        None => println!("no equilibrium found"),
+    }
+    Ok(())
+}
+
+fn run_play(
+    rules: Option<PathBuf>,
+    spec: &str,
+    seed: u64,
+    guide: Option<PathBuf>,
+    transcript: Option<PathBuf>,
+) -> Result<()> {
+    let (rules, guide) = match spec {
+        "guide" => load(&guide.unwrap_or_else(|| DAY.default_input()), rules)?,
+        _ => (load_rules(rules)?, Vec::new()),
+    };
+    let mut bot = bots::build(spec, &rules, &guide, seed)?;
+    let mut transcript: BufWriter<Box<dyn Write>> = BufWriter::new(match transcript {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(io::sink()),
+    });
+
+    let session = play::play(
+        &rules,
+        bot.as_mut(),
+        &mut io::stdin().lock(),
+        &mut io::stdout().lock(),
+        &mut transcript,
+    )?;
+    println!(
+        "{} rounds, you {}, {} {}",
+        session.rounds,
+        session.score,
+        bot.name(),
+        session.bot_score
+    );
+    Ok(())
+}
+
+struct SynthArgs {
+    target: u64,
+    rounds: Option<usize>,
+    part: u8,
+    opponents: Option<PathBuf>,
+    output: Option<PathBuf>,
+}
+
+fn run_synth(rules: Option<PathBuf>, args: SynthArgs) -> Result<()> {
+    let part = Part::try_from(args.part)?;
+    let (rules, column) = match &args.opponents {
+        Some(path) => load(path, rules)?,
+        None => (load_rules(rules)?, Vec::new()),
+    };
+    let opponents = match &args.opponents {
+        Some(path) => {
+            Some(strategy::opponent_column(&rules, &column).map_err(|err| err.in_file(path))?)
+        }
+        None => None,
+    };
+    let rounds = args
+        .rounds
+        .or(opponents.as_ref().map(Vec::len))
+        .unwrap_or_default();
+
+    let guide = synth::synthesize(&rules, part, rounds, opponents.as_deref(), args.target)?
+        .ok_or_else(|| {
+            Error::invalid(format!(
+                "no guide of {} rounds scores {} in part {}",
+                rounds, args.target, part
+            ))
+        })?;
+    let mut out: BufWriter<Box<dyn Write>> = BufWriter::new(match &args.output {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(io::stdout().lock()),
+    });
+    for line in guide {
+        writeln!(out, "{}", line)?;
+    }
+    out.flush()?;
+    Ok(())
 }
 
-fn solve() {
-    let strategies = load_strategies(STRATEGY_FILE);
-    let mut base_result = 0;
-    let mut fixed_result = 0;
-    for strategy in strategies {
-        let game: Game = strategy.as_str().into();
-        let fixed_game: FixedGame = strategy.as_str().into();
-        base_result += game.result();
-        fixed_result += fixed_game.result()
+fn run(cli: Cli) -> Result<()> {
+    match cli.command {
+        Some(Command::Mappings { input, target }) => run_mappings(
+            input.unwrap_or_else(|| DAY.default_input()),
+            cli.rules,
+            target,
+        ),
+        Some(Command::Explain {
+            input,
+            part,
+            csv,
+            output,
+        }) => run_explain(
+            input.unwrap_or_else(|| DAY.default_input()),
+            cli.rules,
+            part,
+            csv,
+            output,
+        ),
+        Some(Command::Tournament {
+            input,
+            bracket,
+            rounds,
+            seed,
+            bots,
+        }) => run_tournament(
+            input.unwrap_or_else(|| DAY.default_input()),
+            cli.rules,
+            bracket,
+            rounds,
+            seed,
+            &bots,
+        ),
+        Some(Command::Strategy { input, per_round }) => run_strategy(
+            input.unwrap_or_else(|| DAY.default_input()),
+            cli.rules,
+            per_round,
+        ),
+        Some(Command::Play {
+            bot,
+            seed,
+            guide,
+            transcript,
+        }) => run_play(cli.rules, &bot, seed, guide, transcript),
+        Some(Command::Synth {
+            target,
+            rounds,
+            part,
+            opponents,
+            output,
+        }) => run_synth(
+            cli.rules,
+            SynthArgs {
+                target,
+                rounds,
+                part,
+                opponents,
+                output,
+            },
+        ),
+        None => {
+            let path = cli.input.unwrap_or_else(|| DAY.default_input());
+            match cli.rules {
+                None => DAY.run(&Part::ALL, path, print_answer),
+                Some(rules) => run_with_rules(path, rules),
+            }
+        }
     }
-    info!("Base game: {:?}", base_result);
-    info!("Fixed game: {:?}", fixed_result);
 }
 
-fn main() {
-    ::std::env::set_var("RUST_LOG", "info");
+fn main() -> ExitCode {
     env_logger::init();
-    solve();
+    day::exit(run(Cli::parse()))
 }
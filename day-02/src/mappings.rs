@@ -0,0 +1,180 @@
+use std::{collections::HashMap, fmt};
+
+use aoc_common::{Error, Result};
+
+use crate::{solve, FixedGame, Game, Outcome, Round, Rules};
+
+/// Beyond this many shapes there are too many ways to read our moves.
+pub const MAX_PERMUTED: usize = 8;
+
+/// What our column of the guide stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reading {
+    /// The shape to play, like [`Game`].
+    Moves,
+    /// How the round has to end, like [`FixedGame`].
+    Outcomes,
+}
+
+impl fmt::Display for Reading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reading::Moves => f.pad("moves"),
+            Reading::Outcomes => f.pad("outcomes"),
+        }
+    }
+}
+
+/// One way to read our column and what the guide scores with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mapping {
+    pub reading: Reading,
+    /// Each of our codes and what it stands for, in code order.
+    pub meanings: Vec<(String, String)>,
+    pub total: u32,
+}
+
+impl fmt::Display for Mapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let meanings: Vec<String> = self
+            .meanings
+            .iter()
+            .map(|(code, meaning)| format!("{}={}", code, meaning))
+            .collect();
+        f.write_str(&meanings.join(", "))
+    }
+}
+
+/// Every order of `0..n`, the identity first.
+fn permutations(n: usize) -> Vec<Vec<usize>> {
+    let mut order: Vec<usize> = (0..n).collect();
+    let mut all = vec![order.clone()];
+    loop {
+        let Some(pivot) = (1..n).rev().find(|&idx| order[idx - 1] < order[idx]) else {
+            return all;
+        };
+        let swap = (pivot..n)
+            .rev()
+            .find(|&idx| order[idx] > order[pivot - 1])
+            .expect("the pivot has a bigger element after it");
+        order.swap(pivot - 1, swap);
+        order[pivot..].reverse();
+        all.push(order.clone());
+    }
+}
+
+/// Total of the guide, each distinct line scored once.
+fn score<'r, M: Round<'r>>(rules: &'r Rules, lines: &HashMap<&str, u32>) -> Result<u32> {
+    lines.iter().try_fold(0, |total, (line, &count)| {
+        Ok(total + M::read(rules, line).map_err(Error::from)?.result() * count)
+    })
+}
+
+/// Scores the guide with every assignment of our codes to shapes and to
+/// outcomes. Best first; on a tie, the puzzle's own readings come first.
+pub fn mappings(rules: &Rules, guide: &[String]) -> Result<Vec<Mapping>> {
+    if rules.len() > MAX_PERMUTED {
+        return Err(Error::invalid(format!(
+            "{} shapes have too many readings, at most {} can be permuted",
+            rules.len(),
+            MAX_PERMUTED
+        )));
+    }
+    // Point at the line when the guide doesn't parse at all.
+    solve::<Game>(rules, guide)?;
+    solve::<FixedGame>(rules, guide)?;
+
+    let mut lines: HashMap<&str, u32> = HashMap::new();
+    for line in guide {
+        *lines.entry(line.as_str()).or_default() += 1;
+    }
+
+    let mut mappings = Vec::new();
+    for order in permutations(rules.len()) {
+        let mapped = rules.with_player_codes(&order);
+        let meanings = rules
+            .shapes()
+            .map(|shape| {
+                let code = &shape.rule().player_code;
+                let meaning = mapped
+                    .player_move(code)
+                    .expect("the codes are only reordered");
+                (code.clone(), meaning.name().to_string())
+            })
+            .collect();
+        mappings.push(Mapping {
+            reading: Reading::Moves,
+            meanings,
+            total: score::<Game>(&mapped, &lines)?,
+        });
+    }
+    for order in permutations(Outcome::ALL.len()) {
+        let mapped = rules.with_outcome_codes(&order);
+        let meanings = Outcome::ALL
+            .iter()
+            .map(|&outcome| {
+                let code = &rules.outcome_rule(outcome).code;
+                let meaning = mapped.outcome(code).expect("the codes are only reordered");
+                (code.clone(), meaning.to_string())
+            })
+            .collect();
+        mappings.push(Mapping {
+            reading: Reading::Outcomes,
+            meanings,
+            total: score::<FixedGame>(&mapped, &lines)?,
+        });
+    }
+
+    mappings.sort_by_key(|mapping| std::cmp::Reverse(mapping.total));
+    Ok(mappings)
+}
+
+/// The mappings that make the guide score exactly `target`.
+pub fn scoring(mappings: &[Mapping], target: u32) -> impl Iterator<Item = &Mapping> {
+    mappings
+        .iter()
+        .filter(move |mapping| mapping.total == target)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::input;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../data/example");
+
+    #[test]
+    fn every_reading_of_the_example() {
+        let guide = input::lines(EXAMPLE.as_bytes()).unwrap();
+        let mappings = mappings(Rules::classic(), &guide).unwrap();
+        assert_eq!(mappings.len(), 12);
+        assert!(mappings
+            .windows(2)
+            .all(|pair| pair[0].total >= pair[1].total));
+
+        let puzzle: Vec<String> = scoring(&mappings, 15).map(ToString::to_string).collect();
+        assert_eq!(puzzle[0], "X=Rock, Y=Paper, Z=Scissors");
+        let fixed = scoring(&mappings, 12)
+            .find(|mapping| mapping.reading == Reading::Outcomes)
+            .unwrap();
+        assert_eq!(fixed.to_string(), "X=Loss, Y=Draw, Z=Win");
+        assert_eq!(scoring(&mappings, 1000).count(), 0);
+    }
+
+    #[test]
+    fn permutations_in_order() {
+        assert_eq!(
+            permutations(3),
+            [
+                [0, 1, 2],
+                [0, 2, 1],
+                [1, 0, 2],
+                [1, 2, 0],
+                [2, 0, 1],
+                [2, 1, 0]
+            ]
+        );
+        assert_eq!(permutations(5).len(), 120);
+    }
+}
@@ -0,0 +1,115 @@
+use std::io::{BufRead, Write};
+
+use aoc_common::Result;
+
+use crate::{tournament::Player, Game, Match, Rules, Shape};
+
+/// Where a session ended up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Session {
+    pub rounds: usize,
+    pub score: u32,
+    pub bot_score: u32,
+}
+
+/// Our code for a shape, or its name in any case.
+fn parse_move<'r>(rules: &'r Rules, text: &str) -> Option<Shape<'r>> {
+    rules.player_move(text).or_else(|| {
+        rules
+            .shapes()
+            .find(|shape| shape.name().eq_ignore_ascii_case(text))
+    })
+}
+
+/// Plays rounds against `bot` with moves read from `input`, one per line,
+/// until it ends or says `q`. Every round is written to `transcript` as a
+/// guide line, the bot in the opponent's column.
+pub fn play<'r>(
+    rules: &'r Rules,
+    bot: &mut dyn Player<'r>,
+    input: &mut dyn BufRead,
+    out: &mut dyn Write,
+    transcript: &mut dyn Write,
+) -> Result<Session> {
+    let mut session = Session::default();
+    let mut line = String::new();
+    let mut theirs = bot.play();
+
+    loop {
+        write!(
+            out,
+            "round {}, your move ({}, q to quit): ",
+            session.rounds + 1,
+            rules.player_codes()
+        )?;
+        out.flush()?;
+        line.clear();
+        if input.read_line(&mut line)? == 0 {
+            writeln!(out)?;
+            break;
+        }
+        let text = line.trim();
+        if text == "q" {
+            break;
+        }
+        let Some(ours) = parse_move(rules, text) else {
+            writeln!(out, "unknown move {:?}", text)?;
+            continue;
+        };
+
+        let game = Game::new(theirs, ours);
+        let score = game.result();
+        session.rounds += 1;
+        session.score += score;
+        session.bot_score += Game::new(ours, theirs).result();
+        writeln!(
+            out,
+            "{} vs {}: {}, +{} (you {}, {} {})",
+            ours,
+            theirs,
+            game.outcome(),
+            score,
+            session.score,
+            bot.name(),
+            session.bot_score
+        )?;
+        writeln!(
+            transcript,
+            "{} {}",
+            theirs.rule().opponent_code,
+            ours.rule().player_code
+        )?;
+        transcript.flush()?;
+
+        bot.observe(ours);
+        theirs = bot.play();
+    }
+    Ok(session)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bots::Constant, solve};
+    use aoc_common::input;
+
+    #[test]
+    fn transcript_replays_to_the_same_score() {
+        let rules = Rules::classic();
+        let mut bot = Constant::new(rules.shape("Rock").unwrap());
+        let mut input = "Y\nlizard\nscissors\nq\nX\n".as_bytes();
+        let (mut out, mut transcript) = (Vec::new(), Vec::new());
+
+        let session = play(rules, &mut bot, &mut input, &mut out, &mut transcript).unwrap();
+        assert_eq!(session.rounds, 2);
+        assert_eq!((session.score, session.bot_score), (8 + 3, 1 + 7));
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("unknown move \"lizard\""));
+        assert!(out.contains("Scissors vs Rock: Loss, +3 (you 11, constant:Rock 8)"));
+
+        let guide = input::lines(transcript.as_slice()).unwrap();
+        assert_eq!(guide, ["A Y", "A Z"]);
+        assert_eq!(solve::<Game>(rules, &guide).unwrap(), session.score);
+    }
+}
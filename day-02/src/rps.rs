@@ -1,106 +1,113 @@
-use core::panic;
+use aoc_common::{Diagnostic, Tokens};
 use log::debug;
-use std::cmp::Ordering;
+use std::{cmp::Ordering, fmt, str::FromStr};
 
-#[derive(Debug, Clone, Copy)]
-enum RPS {
-    Rock = 1,
-    Paper,
-    Scissors,
+use crate::{Rules, Shape};
+
+pub(crate) type ParseResult<T> = std::result::Result<T, Diagnostic>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
 }
 
-enum Result {
-    Loss = 0,
-    Draw = 3,
-    Win = 6,
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
 }
-pub struct Game {
-    player: RPS,
-    opponent: RPS,
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Outcome::Loss => "Loss",
+            Outcome::Draw => "Draw",
+            Outcome::Win => "Win",
+        })
+    }
 }
 
-pub struct FixedGame {
-    opponent: RPS,
-    result: Result,
+#[derive(Debug, Clone, Copy)]
+pub struct Game<'r> {
+    player: Shape<'r>,
+    opponent: Shape<'r>,
+}
+
+pub struct FixedGame<'r> {
+    opponent: Shape<'r>,
+    result: Outcome,
 }
 
 pub trait Match {
     fn result(&self) -> u32;
 }
 
-trait Mechanic {
-    fn loses_to(&self) -> RPS;
-    fn wins_against(&self) -> RPS;
+/// A line of the strategy guide, read with the codes of a set of rules.
+pub trait Round<'r>: Match + Sized {
+    fn read(rules: &'r Rules, line: &str) -> ParseResult<Self>;
+    /// The moves actually played.
+    fn game(&self) -> Game<'r>;
 }
 
-trait FixMatch {
-    fn fix_match(&self) -> RPS;
-}
+impl<'r> Game<'r> {
+    /// Both shapes have to follow the same rules.
+    pub fn new(opponent: Shape<'r>, player: Shape<'r>) -> Game<'r> {
+        debug_assert!(std::ptr::eq(opponent.rules(), player.rules()));
+        Game { player, opponent }
+    }
 
-impl Mechanic for RPS {
-    fn loses_to(&self) -> RPS {
-        let code = (*self as i32 % 3) + 1;
-        RPS::from(code)
+    pub fn opponent(&self) -> Shape<'r> {
+        self.opponent
     }
-    fn wins_against(&self) -> RPS {
-        let code = ((*self as u32 + 1) % 3) + 1;
-        RPS::from(code)
+
+    pub fn player(&self) -> Shape<'r> {
+        self.player
     }
-}
 
-impl From<&str> for RPS {
-    fn from(value: &str) -> Self {
-        match value {
-            "A" | "X" => RPS::Rock,
-            "B" | "Y" => RPS::Paper,
-            "C" | "Z" => RPS::Scissors,
-            _ => panic!("Unknown move"),
+    pub fn outcome(&self) -> Outcome {
+        if self.player > self.opponent {
+            Outcome::Win
+        } else if self.player == self.opponent {
+            Outcome::Draw
+        } else {
+            Outcome::Loss
         }
     }
 }
 
-impl From<&str> for Result {
-    fn from(value: &str) -> Self {
-        match value {
-            "X" => Result::Loss,
-            "Y" => Result::Draw,
-            "Z" => Result::Win,
-            _ => panic!("Unknown result"),
-        }
-    }
+pub(crate) trait Mechanic {
+    fn loses_to(&self) -> Self;
+    fn wins_against(&self) -> Self;
 }
 
-impl From<u32> for RPS {
-    fn from(value: u32) -> Self {
-        match value {
-            1 => RPS::Rock,
-            2 => RPS::Paper,
-            3 => RPS::Scissors,
-            _ => panic!("No such rps"),
-        }
-    }
+trait FixMatch<'r> {
+    fn fix_match(&self) -> Shape<'r>;
 }
 
-impl From<i32> for RPS {
-    fn from(value: i32) -> Self {
-        match value {
-            1 => RPS::Rock,
-            2 => RPS::Paper,
-            3 => RPS::Scissors,
-            _ => panic!("No such rps"),
-        }
-    }
+/// The best scoring shape that gets `outcome` against `opponent`; the first
+/// one on a tie.
+fn answer<'r>(opponent: &Shape<'r>, outcome: Outcome) -> Shape<'r> {
+    opponent
+        .rules()
+        .shapes()
+        .rev()
+        .filter(|shape| shape.against(opponent) == outcome)
+        .max_by_key(Shape::score)
+        .expect("balanced rules have an answer for every outcome")
 }
 
-impl PartialEq for RPS {
-    fn eq(&self, other: &Self) -> bool {
-        *self as u32 == *other as u32
+impl Mechanic for Shape<'_> {
+    fn loses_to(&self) -> Self {
+        answer(self, Outcome::Win)
+    }
+    fn wins_against(&self) -> Self {
+        answer(self, Outcome::Loss)
     }
 }
 
-impl PartialOrd for RPS {
+impl PartialOrd for Shape<'_> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self.wins_against() == *other {
+        if self.beats(other) {
             debug!("{:?} wins over {:?}", *self, *other);
             return Some(Ordering::Greater);
         } else if self == other {
@@ -112,63 +119,108 @@ impl PartialOrd for RPS {
     }
 }
 
-impl From<&str> for Game {
-    fn from(value: &str) -> Self {
-        let moves = value.split(" ").collect::<Vec<&str>>();
-        if moves.len() != 2 {
-            panic!("Invalid number of moves");
-        }
+fn lookup<'a, T>(
+    line: &str,
+    words: &mut Tokens<'a>,
+    expected: String,
+    find: impl FnOnce(&'a str) -> Option<T>,
+) -> ParseResult<T> {
+    let token = words.next(&expected)?;
+    find(token).ok_or_else(|| Diagnostic::unexpected(line, token, expected))
+}
+
+pub(crate) fn opponent_move<'r>(
+    rules: &'r Rules,
+    line: &str,
+    words: &mut Tokens,
+) -> ParseResult<Shape<'r>> {
+    let expected = format!("the opponent's move, {}", rules.opponent_codes());
+    lookup(line, words, expected, |code| rules.opponent_move(code))
+}
+
+impl<'r> Round<'r> for Game<'r> {
+    fn read(rules: &'r Rules, line: &str) -> ParseResult<Self> {
+        let mut moves = Tokens::new(line);
+        let game = Game {
+            opponent: opponent_move(rules, line, &mut moves)?,
+            player: lookup(
+                line,
+                &mut moves,
+                format!("your move, {}", rules.player_codes()),
+                |code| rules.player_move(code),
+            )?,
+        };
+        moves.end()?;
+        Ok(game)
+    }
+
+    fn game(&self) -> Game<'r> {
+        *self
+    }
+}
 
+impl<'r> Round<'r> for FixedGame<'r> {
+    fn read(rules: &'r Rules, line: &str) -> ParseResult<Self> {
+        let mut entry = Tokens::new(line);
+        let game = FixedGame {
+            opponent: opponent_move(rules, line, &mut entry)?,
+            result: lookup(
+                line,
+                &mut entry,
+                format!("the result, {}", rules.outcome_codes()),
+                |code| rules.outcome(code),
+            )?,
+        };
+        entry.end()?;
+        Ok(game)
+    }
+
+    fn game(&self) -> Game<'r> {
         Game {
-            opponent: moves[0].into(),
-            player: moves[1].into(),
+            opponent: self.opponent,
+            player: self.fix_match(),
         }
     }
 }
 
-impl From<&str> for FixedGame {
-    fn from(value: &str) -> Self {
-        let entry = value.split(" ").collect::<Vec<&str>>();
+impl FromStr for Game<'static> {
+    type Err = Diagnostic;
 
-        FixedGame {
-            opponent: entry[0].into(),
-            result: entry[1].into(),
-        }
+    fn from_str(value: &str) -> ParseResult<Self> {
+        Game::read(Rules::classic(), value)
+    }
+}
+
+impl FromStr for FixedGame<'static> {
+    type Err = Diagnostic;
+
+    fn from_str(value: &str) -> ParseResult<Self> {
+        FixedGame::read(Rules::classic(), value)
     }
 }
 
-impl Match for Game {
+impl Match for Game<'_> {
     fn result(&self) -> u32 {
-        let mut result = self.player as u32;
+        let mut result = self.player.score();
         debug!("Points for sign: {:?}", result);
-        if self.player > self.opponent {
-            result += Result::Win as u32;
-        } else if self.player == self.opponent {
-            result += Result::Draw as u32;
-        } else {
-            result += Result::Loss as u32;
-        }
+        result += self.player.rules().outcome_rule(self.outcome()).score;
         debug!("Score: {:?}", result);
         result
     }
 }
 
-impl FixMatch for FixedGame {
-    fn fix_match(&self) -> RPS {
+impl<'r> FixMatch<'r> for FixedGame<'r> {
+    fn fix_match(&self) -> Shape<'r> {
         match self.result {
-            Result::Draw => return self.opponent,
-            Result::Loss => return self.opponent.wins_against(),
-            Result::Win => return self.opponent.loses_to(),
+            Outcome::Draw => self.opponent,
+            Outcome::Loss => self.opponent.wins_against(),
+            Outcome::Win => self.opponent.loses_to(),
         }
     }
 }
 
-impl Match for FixedGame {
+impl Match for FixedGame<'_> {
     fn result(&self) -> u32 {
-        Game {
-            opponent: self.opponent,
-            player: self.fix_match(),
-        }
-        .result()
+        self.game().result()
     }
 }
@@ -0,0 +1,363 @@
+use std::{fmt, ptr, sync::LazyLock};
+
+use aoc_common::{Error, Result};
+
+use crate::Outcome;
+
+/// Most shapes a game can have; each one's opponents fit a u64.
+pub const MAX_SHAPES: usize = 64;
+
+/// One shape, as written in a rules table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShapeRule {
+    pub name: String,
+    /// Points for playing it.
+    pub score: u32,
+    /// Its letter in the opponent's column.
+    pub opponent_code: String,
+    /// Its letter in our column, when the column is a move.
+    pub player_code: String,
+    /// Names of the shapes it beats.
+    pub beats: Vec<String>,
+}
+
+impl ShapeRule {
+    pub fn new(name: &str, score: u32, codes: (&str, &str), beats: &[&str]) -> ShapeRule {
+        ShapeRule {
+            name: name.to_string(),
+            score,
+            opponent_code: codes.0.to_string(),
+            player_code: codes.1.to_string(),
+            beats: beats.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+/// Points and letter for a loss, a draw or a win.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutcomeRule {
+    pub score: u32,
+    /// Its letter in our column, when the column is an outcome.
+    pub code: String,
+}
+
+impl OutcomeRule {
+    pub fn new(score: u32, code: &str) -> OutcomeRule {
+        OutcomeRule {
+            score,
+            code: code.to_string(),
+        }
+    }
+}
+
+/// A game of N shapes where every shape beats exactly half of the others.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    shapes: Vec<ShapeRule>,
+    /// Bit `b` of `beats[a]` is set when shape `a` beats shape `b`.
+    beats: Vec<u64>,
+    /// Indexed by [`Outcome`].
+    outcomes: [OutcomeRule; 3],
+}
+
+static CLASSIC: LazyLock<Rules> = LazyLock::new(|| {
+    Rules::new(
+        vec![
+            ShapeRule::new("Rock", 1, ("A", "X"), &["Scissors"]),
+            ShapeRule::new("Paper", 2, ("B", "Y"), &["Rock"]),
+            ShapeRule::new("Scissors", 3, ("C", "Z"), &["Paper"]),
+        ],
+        [
+            OutcomeRule::new(0, "X"),
+            OutcomeRule::new(3, "Y"),
+            OutcomeRule::new(6, "Z"),
+        ],
+    )
+    .expect("the classic rules are valid")
+});
+
+fn unique<'a>(what: &str, codes: impl Iterator<Item = &'a str>) -> Result<()> {
+    let mut seen: Vec<&str> = Vec::new();
+    for code in codes {
+        if code.is_empty() || code.contains(char::is_whitespace) {
+            return Err(Error::invalid(format!(
+                "{} {:?} isn't a single word",
+                what, code
+            )));
+        }
+        if seen.contains(&code) {
+            return Err(Error::invalid(format!("{} {:?} is used twice", what, code)));
+        }
+        seen.push(code);
+    }
+    Ok(())
+}
+
+/// `A, B or C`
+pub(crate) fn one_of<'a>(codes: impl Iterator<Item = &'a str>) -> String {
+    let codes: Vec<&str> = codes.collect();
+    match codes.split_last() {
+        Some((last, [])) => last.to_string(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => String::new(),
+    }
+}
+
+impl Rules {
+    /// Checks that every pair of shapes has a winner and that the game is
+    /// balanced, so any shape can be answered with a loss, a draw or a win.
+    pub fn new(shapes: Vec<ShapeRule>, outcomes: [OutcomeRule; 3]) -> Result<Rules> {
+        let n = shapes.len();
+        if n < 3 || n.is_multiple_of(2) || n > MAX_SHAPES {
+            return Err(Error::invalid(format!(
+                "a game needs an odd number of shapes between 3 and {}, not {}",
+                MAX_SHAPES, n
+            )));
+        }
+        unique("shape", shapes.iter().map(|shape| shape.name.as_str()))?;
+        unique(
+            "code",
+            shapes.iter().map(|shape| shape.opponent_code.as_str()),
+        )?;
+        unique(
+            "code",
+            shapes.iter().map(|shape| shape.player_code.as_str()),
+        )?;
+        unique("code", outcomes.iter().map(|outcome| outcome.code.as_str()))?;
+
+        let mut beats = vec![0u64; n];
+        for (idx, shape) in shapes.iter().enumerate() {
+            for name in &shape.beats {
+                let other = shapes
+                    .iter()
+                    .position(|other| &other.name == name)
+                    .ok_or_else(|| {
+                        Error::invalid(format!(
+                            "{} beats {:?}, which isn't a shape",
+                            shape.name, name
+                        ))
+                    })?;
+                if other == idx {
+                    return Err(Error::invalid(format!("{} can't beat itself", shape.name)));
+                }
+                beats[idx] |= 1 << other;
+            }
+        }
+
+        for (a, first) in shapes.iter().enumerate() {
+            if beats[a].count_ones() as usize != n / 2 {
+                return Err(Error::invalid(format!(
+                    "{} has to beat exactly {} shapes, not {}",
+                    first.name,
+                    n / 2,
+                    beats[a].count_ones()
+                )));
+            }
+            for (b, second) in shapes.iter().enumerate().skip(a + 1) {
+                if (beats[a] >> b & 1) == (beats[b] >> a & 1) {
+                    return Err(Error::invalid(format!(
+                        "exactly one of {} and {} has to beat the other",
+                        first.name, second.name
+                    )));
+                }
+            }
+        }
+
+        Ok(Rules {
+            shapes,
+            beats,
+            outcomes,
+        })
+    }
+
+    /// Rock, Paper, Scissors as the puzzle plays it.
+    pub fn classic() -> &'static Rules {
+        &CLASSIC
+    }
+
+    /// Rock, Paper, Scissors, Lizard, Spock, scored 1 to 5 in that order,
+    /// with A to E against V to Z.
+    pub fn lizard_spock() -> Rules {
+        Rules::new(
+            vec![
+                ShapeRule::new("Rock", 1, ("A", "V"), &["Scissors", "Lizard"]),
+                ShapeRule::new("Paper", 2, ("B", "W"), &["Rock", "Spock"]),
+                ShapeRule::new("Scissors", 3, ("C", "X"), &["Paper", "Lizard"]),
+                ShapeRule::new("Lizard", 4, ("D", "Y"), &["Paper", "Spock"]),
+                ShapeRule::new("Spock", 5, ("E", "Z"), &["Rock", "Scissors"]),
+            ],
+            Rules::classic().outcomes.clone(),
+        )
+        .expect("the lizard spock rules are valid")
+    }
+
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    pub fn shapes(&self) -> impl DoubleEndedIterator<Item = Shape<'_>> {
+        (0..self.len()).map(|index| Shape { rules: self, index })
+    }
+
+    pub fn shape(&self, name: &str) -> Option<Shape<'_>> {
+        self.shapes().find(|shape| shape.name() == name)
+    }
+
+    pub fn opponent_move(&self, code: &str) -> Option<Shape<'_>> {
+        self.shapes()
+            .find(|shape| shape.rule().opponent_code == code)
+    }
+
+    pub fn player_move(&self, code: &str) -> Option<Shape<'_>> {
+        self.shapes().find(|shape| shape.rule().player_code == code)
+    }
+
+    pub fn outcome(&self, code: &str) -> Option<Outcome> {
+        Outcome::ALL
+            .into_iter()
+            .find(|&outcome| self.outcomes[outcome as usize].code == code)
+    }
+
+    pub fn outcome_rule(&self, outcome: Outcome) -> &OutcomeRule {
+        &self.outcomes[outcome as usize]
+    }
+
+    /// The same game with our move codes handed out again: shape `i` gets
+    /// the code shape `order[i]` has now.
+    pub fn with_player_codes(&self, order: &[usize]) -> Rules {
+        let mut rules = self.clone();
+        for (shape, &from) in rules.shapes.iter_mut().zip(order) {
+            shape.player_code = self.shapes[from].player_code.clone();
+        }
+        rules
+    }
+
+    /// The same game with the outcome codes handed out again, like
+    /// [`Rules::with_player_codes`], in [`Outcome::ALL`] order.
+    pub fn with_outcome_codes(&self, order: &[usize]) -> Rules {
+        let mut rules = self.clone();
+        for (outcome, &from) in rules.outcomes.iter_mut().zip(order) {
+            outcome.code = self.outcomes[from].code.clone();
+        }
+        rules
+    }
+
+    pub(crate) fn opponent_codes(&self) -> String {
+        one_of(self.shapes.iter().map(|shape| shape.opponent_code.as_str()))
+    }
+
+    pub(crate) fn player_codes(&self) -> String {
+        one_of(self.shapes.iter().map(|shape| shape.player_code.as_str()))
+    }
+
+    pub(crate) fn outcome_codes(&self) -> String {
+        one_of(self.outcomes.iter().map(|outcome| outcome.code.as_str()))
+    }
+}
+
+/// A move in a game, tied to the rules it follows.
+#[derive(Clone, Copy)]
+pub struct Shape<'r> {
+    rules: &'r Rules,
+    index: usize,
+}
+
+impl<'r> Shape<'r> {
+    pub fn rules(&self) -> &'r Rules {
+        self.rules
+    }
+
+    /// Position in the rules table.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn rule(&self) -> &'r ShapeRule {
+        &self.rules.shapes[self.index]
+    }
+
+    pub fn name(&self) -> &'r str {
+        &self.rule().name
+    }
+
+    pub fn score(&self) -> u32 {
+        self.rule().score
+    }
+
+    pub fn beats(&self, other: &Shape) -> bool {
+        self.rules.beats[self.index] >> other.index & 1 == 1
+    }
+
+    /// How playing this against `opponent` ends.
+    pub fn against(&self, opponent: &Shape) -> Outcome {
+        if self == opponent {
+            Outcome::Draw
+        } else if self.beats(opponent) {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+}
+
+impl PartialEq for Shape<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.rules, other.rules) && self.index == other.index
+    }
+}
+
+impl Eq for Shape<'_> {}
+
+impl fmt::Debug for Shape<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl fmt::Display for Shape<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lizard_spock_is_balanced() {
+        let rules = Rules::lizard_spock();
+        let spock = rules.shape("Spock").unwrap();
+        let beaten: Vec<&str> = rules
+            .shapes()
+            .filter(|shape| spock.beats(shape))
+            .map(|shape| shape.name())
+            .collect();
+        assert_eq!(beaten, ["Rock", "Scissors"]);
+        assert_eq!(
+            rules.player_move("Y").map(|shape| shape.name()),
+            Some("Lizard")
+        );
+    }
+
+    #[test]
+    fn unbalanced_rules_are_rejected() {
+        let outcomes = Rules::classic().outcomes.clone();
+        let lopsided = vec![
+            ShapeRule::new("Rock", 1, ("A", "X"), &["Scissors", "Paper"]),
+            ShapeRule::new("Paper", 2, ("B", "Y"), &[]),
+            ShapeRule::new("Scissors", 3, ("C", "Z"), &["Paper"]),
+        ];
+        assert!(Rules::new(lopsided, outcomes.clone()).is_err());
+
+        let mut shapes = Rules::classic().shapes.clone();
+        shapes[1].player_code = "X".to_string();
+        assert!(Rules::new(shapes, outcomes.clone()).is_err());
+
+        assert!(Rules::new(Rules::classic().shapes[..2].to_vec(), outcomes).is_err());
+    }
+}
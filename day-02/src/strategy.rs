@@ -0,0 +1,248 @@
+use aoc_common::{Diagnostic, Error, Result, Tokens};
+
+use crate::{rps::opponent_move, Game, Match, Rules, Shape};
+
+/// Most shapes [`equilibrium`] searches; it tries every subset of them.
+pub const MAX_EQUILIBRIUM_SHAPES: usize = 16;
+
+/// How far off a probability or a payoff may be and still count.
+const EPSILON: f64 = 1e-9;
+
+/// `payoff[ours][theirs]`: what [`Match::result`] gives us for playing
+/// shape `ours` against `theirs`. The opponent scores the transpose.
+pub fn payoff(rules: &Rules) -> Vec<Vec<u32>> {
+    rules
+        .shapes()
+        .map(|ours| {
+            rules
+                .shapes()
+                .map(|theirs| Game::new(theirs, ours).result())
+                .collect()
+        })
+        .collect()
+}
+
+/// The first column of the guide, whatever the second one means.
+pub fn opponent_column<'r>(rules: &'r Rules, guide: &[String]) -> Result<Vec<Shape<'r>>> {
+    guide
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| {
+            let mut words = Tokens::new(line);
+            opponent_move(rules, line, &mut words)
+                .map_err(|err: Diagnostic| Error::from(err.at_line(idx + 1)))
+        })
+        .collect()
+}
+
+/// The shape scoring the most against `opponent`; the first one on a tie.
+pub fn best_response<'r>(opponent: Shape<'r>) -> (Shape<'r>, u32) {
+    opponent
+        .rules()
+        .shapes()
+        .rev()
+        .map(|shape| (shape, Game::new(opponent, shape).result()))
+        .max_by_key(|&(_, score)| score)
+        .expect("rules have shapes")
+}
+
+/// Solves `matrix · x = rhs` by Gaussian elimination, `None` if singular.
+fn solve_linear(mut matrix: Vec<Vec<f64>>, mut rhs: Vec<f64>) -> Option<Vec<f64>> {
+    let n = rhs.len();
+    for col in 0..n {
+        let pivot =
+            (col..n).max_by(|&a, &b| matrix[a][col].abs().total_cmp(&matrix[b][col].abs()))?;
+        if matrix[pivot][col].abs() < EPSILON {
+            return None;
+        }
+        matrix.swap(col, pivot);
+        rhs.swap(col, pivot);
+        let pivot_row = matrix[col].clone();
+        for row in col + 1..n {
+            let factor = matrix[row][col] / pivot_row[col];
+            for (cell, &value) in matrix[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                *cell -= factor * value;
+            }
+            rhs[row] -= factor * rhs[col];
+        }
+    }
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let known: f64 = (row + 1..n).map(|idx| matrix[row][idx] * x[idx]).sum();
+        x[row] = (rhs[row] - known) / matrix[row][row];
+    }
+    Some(x)
+}
+
+/// A mix of shapes that both players can use without either gaining by
+/// switching, for the game where each scores their own [`Match::result`].
+/// Tries supports from the smallest up, so a pure equilibrium wins over a
+/// mixed one. `None` when there are too many shapes to search, or when no
+/// support gives a unique mix.
+pub fn equilibrium(payoff: &[Vec<u32>]) -> Option<Vec<f64>> {
+    let n = payoff.len();
+    if n == 0 || n > MAX_EQUILIBRIUM_SHAPES {
+        return None;
+    }
+    let mut supports: Vec<u32> = (1..1u32 << n).collect();
+    supports.sort_by_key(|support| (support.count_ones(), *support));
+
+    supports.into_iter().find_map(|support| {
+        let shapes: Vec<usize> = (0..n).filter(|&idx| support >> idx & 1 == 1).collect();
+        let k = shapes.len();
+        // Every shape in the support earns the same `v` against the mix, and
+        // the mix adds up to one. Unknowns are the mix, then `v`.
+        let mut matrix = vec![vec![0.0; k + 1]; k + 1];
+        let mut rhs = vec![0.0; k + 1];
+        for (row, &ours) in shapes.iter().enumerate() {
+            for (col, &theirs) in shapes.iter().enumerate() {
+                matrix[row][col] = f64::from(payoff[ours][theirs]);
+            }
+            matrix[row][k] = -1.0;
+        }
+        matrix[k][..k].fill(1.0);
+        rhs[k] = 1.0;
+
+        let solution = solve_linear(matrix, rhs)?;
+        let value = solution[k];
+        if solution[..k].iter().any(|&p| p < -EPSILON) {
+            return None;
+        }
+        let mut mix = vec![0.0; n];
+        for (&shape, &p) in shapes.iter().zip(&solution) {
+            mix[shape] = p.max(0.0);
+        }
+        let stable = (0..n).all(|ours| expected(&payoff[ours], &mix) <= value + EPSILON);
+        stable.then_some(mix)
+    })
+}
+
+/// Average score of a row of the payoff matrix against a mix of shapes.
+pub fn expected(row: &[u32], mix: &[f64]) -> f64 {
+    row.iter()
+        .zip(mix)
+        .map(|(&score, &p)| f64::from(score) * p)
+        .sum()
+}
+
+/// What we can make of the opponent's column.
+#[derive(Debug, Clone)]
+pub struct Analysis<'r> {
+    pub rounds: usize,
+    /// How often the opponent played each shape, as a fraction of rounds.
+    pub distribution: Vec<f64>,
+    /// Our best response and its score, round by round.
+    pub responses: Vec<(Shape<'r>, u32)>,
+    /// Playing the best response every round.
+    pub best_total: u64,
+    /// The one shape scoring the most if played every round, and its total.
+    pub best_constant: (Shape<'r>, u64),
+    /// Score per round of always playing each shape, against the opponent's
+    /// distribution.
+    pub expected: Vec<f64>,
+    pub equilibrium: Option<Vec<f64>>,
+}
+
+impl Analysis<'_> {
+    /// Score per round of playing the equilibrium mix against the
+    /// opponent's distribution.
+    pub fn equilibrium_expected(&self) -> Option<f64> {
+        let mix = self.equilibrium.as_ref()?;
+        Some(self.expected.iter().zip(mix).map(|(e, p)| e * p).sum())
+    }
+}
+
+pub fn analyse<'r>(rules: &'r Rules, guide: &[String]) -> Result<Analysis<'r>> {
+    let opponents = opponent_column(rules, guide)?;
+    let payoff = payoff(rules);
+
+    let mut counts = vec![0u64; rules.len()];
+    for shape in &opponents {
+        counts[shape.index()] += 1;
+    }
+    let rounds = opponents.len();
+    let distribution: Vec<f64> = counts
+        .iter()
+        .map(|&count| count as f64 / rounds.max(1) as f64)
+        .collect();
+
+    let responses: Vec<(Shape, u32)> = opponents
+        .iter()
+        .map(|&shape| best_response(shape))
+        .collect();
+    let best_total = responses.iter().map(|&(_, score)| u64::from(score)).sum();
+
+    let totals = rules.shapes().map(|ours| {
+        let total = counts
+            .iter()
+            .zip(&payoff[ours.index()])
+            .map(|(&count, &score)| count * u64::from(score))
+            .sum::<u64>();
+        (ours, total)
+    });
+    let best_constant = totals
+        .rev()
+        .max_by_key(|&(_, total)| total)
+        .expect("rules have shapes");
+
+    Ok(Analysis {
+        rounds,
+        expected: payoff
+            .iter()
+            .map(|row| expected(row, &distribution))
+            .collect(),
+        distribution,
+        responses,
+        best_total,
+        best_constant,
+        equilibrium: equilibrium(&payoff),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::input;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../data/example");
+
+    #[test]
+    fn example_analysis() {
+        let guide = input::lines(EXAMPLE.as_bytes()).unwrap();
+        let analysis = analyse(Rules::classic(), &guide).unwrap();
+        assert_eq!(analysis.rounds, 3);
+        // Paper on Rock, Scissors on Paper, Rock on Scissors.
+        assert_eq!(analysis.best_total, 8 + 9 + 7);
+        let (shape, total) = analysis.best_constant;
+        assert_eq!((shape.name(), total), ("Scissors", 3 + 9 + 6));
+        assert!((analysis.expected[0] - 4.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn equilibrium_is_stable() {
+        for rules in [Rules::classic().clone(), Rules::lizard_spock()] {
+            let payoff = payoff(&rules);
+            let mix = equilibrium(&payoff).unwrap();
+            assert!((mix.iter().sum::<f64>() - 1.0).abs() < EPSILON);
+            let value: f64 = payoff
+                .iter()
+                .zip(&mix)
+                .map(|(row, p)| expected(row, &mix) * p)
+                .sum();
+            for row in &payoff {
+                assert!(expected(row, &mix) <= value + 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn equal_shapes_mix_evenly() {
+        let rules = Rules::from_toml(
+            "[[shape]]\nname = \"Paper\"\nscore = 1\n[[shape]]\nname = \"Scissors\"\nscore = 1\n",
+        )
+        .unwrap();
+        let mix = equilibrium(&payoff(&rules)).unwrap();
+        assert!(mix.iter().all(|p| (p - 1.0 / 3.0).abs() < EPSILON));
+    }
+}
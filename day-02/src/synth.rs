@@ -0,0 +1,367 @@
+use aoc_common::{Error, Part, Result};
+
+use crate::{FixedGame, Game, Match, Outcome, Round, Rules, Shape};
+
+/// Fixed size set of small totals.
+#[derive(Debug, Clone)]
+struct Bits {
+    words: Vec<u64>,
+}
+
+impl Bits {
+    fn new(len: usize) -> Bits {
+        Bits {
+            words: vec![0; len / 64 + 1],
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.words.len() * 64
+    }
+
+    fn get(&self, idx: usize) -> bool {
+        self.words
+            .get(idx / 64)
+            .is_some_and(|word| word >> (idx % 64) & 1 == 1)
+    }
+
+    fn set(&mut self, idx: usize) {
+        self.words[idx / 64] |= 1 << (idx % 64);
+    }
+
+    /// `self |= other << shift`, dropping whatever falls off the end.
+    fn or_shifted(&mut self, other: &Bits, shift: usize) {
+        let (words, bits) = (shift / 64, shift % 64);
+        for (idx, &word) in other.words.iter().enumerate() {
+            if let Some(target) = self.words.get_mut(idx + words) {
+                *target |= word << bits;
+            }
+            if bits > 0 {
+                if let Some(target) = self.words.get_mut(idx + words + 1) {
+                    *target |= word >> (64 - bits);
+                }
+            }
+        }
+    }
+
+    /// Maximal runs `start, start + step, ...` of set bits as `(start,
+    /// count)`, `step` being the gcd of the gaps between set bits. A
+    /// near-contiguous set is a handful of runs however big it is.
+    fn runs(&self) -> (usize, Vec<(usize, usize)>) {
+        let ones: Vec<usize> = (0..self.len()).filter(|&idx| self.get(idx)).collect();
+        let step = ones
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .fold(0, gcd)
+            .max(1);
+        let mut runs: Vec<(usize, usize)> = Vec::new();
+        for idx in ones {
+            match runs.last_mut() {
+                Some((start, count)) if *start + *count * step == idx => *count += 1,
+                _ => runs.push((idx, 1)),
+            }
+        }
+        (step, runs)
+    }
+
+    /// Every `a + b` with `a` in `self` and `b` in `other`. Works run by run
+    /// through whichever set has fewer, each run by doubling shifts, so it
+    /// takes O(runs · words · log(run length)) rather than a pass over the
+    /// other set per total.
+    fn sumset(&self, other: &Bits) -> Bits {
+        let ((step, runs), other) = match (self.runs(), other.runs()) {
+            (mine, theirs) if mine.1.len() <= theirs.1.len() => (mine, other),
+            (_, theirs) => (theirs, self),
+        };
+        let mut sums = Bits::new(self.len() + other.len());
+        for (start, count) in runs {
+            let mut run = Bits::new(sums.len());
+            run.or_shifted(other, 0);
+            let mut covered = 1;
+            while covered < count {
+                let more = covered.min(count - covered);
+                let copy = run.clone();
+                run.or_shifted(&copy, more * step);
+                covered += more;
+            }
+            sums.or_shifted(&run, start);
+        }
+        sums
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Rounds that offer the same scores: the same opponent, or any opponent.
+struct Group {
+    rounds: Vec<usize>,
+    /// Distinct scores, lowest first, each with a guide line scoring it.
+    choices: Vec<(usize, String)>,
+    /// `fewest[x]`: fewest rounds scoring above the lowest choice that add
+    /// up to `x` above the lowest total, `usize::MAX` if none do.
+    fewest: Vec<usize>,
+}
+
+fn score(rules: &Rules, part: Part, line: &str) -> u32 {
+    match part {
+        Part::One => Game::read(rules, line).map(|game| game.result()),
+        Part::Two => FixedGame::read(rules, line).map(|game| game.result()),
+    }
+    .expect("lines are built from the rules' own codes")
+}
+
+impl Group {
+    fn new<'r>(
+        rules: &'r Rules,
+        part: Part,
+        opponents: impl Iterator<Item = Shape<'r>>,
+        rounds: Vec<usize>,
+    ) -> Group {
+        let codes: Vec<&str> = match part {
+            Part::One => rules
+                .shapes()
+                .map(|shape| shape.rule().player_code.as_str())
+                .collect(),
+            Part::Two => Outcome::ALL
+                .iter()
+                .map(|&outcome| rules.outcome_rule(outcome).code.as_str())
+                .collect(),
+        };
+
+        let mut choices: Vec<(usize, String)> = Vec::new();
+        for opponent in opponents {
+            for code in &codes {
+                let line = format!("{} {}", opponent.rule().opponent_code, code);
+                let value = score(rules, part, &line) as usize;
+                if choices.iter().all(|&(other, _)| other != value) {
+                    choices.push((value, line));
+                }
+            }
+        }
+        choices.sort();
+
+        let lowest = choices[0].0;
+        let span = choices[choices.len() - 1].0 - lowest;
+        let mut fewest = vec![usize::MAX; rounds.len() * span + 1];
+        fewest[0] = 0;
+        for x in 1..fewest.len() {
+            fewest[x] = choices
+                .iter()
+                .map(|&(value, _)| value - lowest)
+                .filter(|&step| step > 0 && step <= x && fewest[x - step] != usize::MAX)
+                .map(|step| fewest[x - step] + 1)
+                .min()
+                .unwrap_or(usize::MAX);
+        }
+
+        Group {
+            rounds,
+            choices,
+            fewest,
+        }
+    }
+
+    /// Whether `count` of the rounds can add up to `total`.
+    fn feasible(&self, total: usize, count: usize) -> bool {
+        let base = self.choices[0].0 * count;
+        total >= base
+            && self
+                .fewest
+                .get(total - base)
+                .is_some_and(|&fewest| fewest <= count)
+    }
+
+    fn max(&self) -> usize {
+        self.choices[self.choices.len() - 1].0 * self.rounds.len()
+    }
+
+    /// Every total all the rounds can add up to.
+    fn totals(&self) -> Bits {
+        let mut totals = Bits::new(self.max());
+        for total in 0..=self.max() {
+            if self.feasible(total, self.rounds.len()) {
+                totals.set(total);
+            }
+        }
+        totals
+    }
+}
+
+/// A guide of `rounds` lines that scores exactly `target` when read as
+/// `part`, its opponent column taken from `opponents` if given. `None` if
+/// no guide does: every way to split the target over the rounds is ruled
+/// out.
+pub fn synthesize(
+    rules: &Rules,
+    part: Part,
+    rounds: usize,
+    opponents: Option<&[Shape]>,
+    target: u64,
+) -> Result<Option<Vec<String>>> {
+    let groups: Vec<Group> = match opponents {
+        None => vec![Group::new(
+            rules,
+            part,
+            rules.shapes(),
+            (0..rounds).collect(),
+        )],
+        Some(column) if column.len() != rounds => {
+            return Err(Error::invalid(format!(
+                "the opponent's column has {} rounds, not {}",
+                column.len(),
+                rounds
+            )))
+        }
+        Some(column) => rules
+            .shapes()
+            .map(|shape| {
+                let rounds = (0..column.len())
+                    .filter(|&idx| column[idx] == shape)
+                    .collect();
+                Group::new(rules, part, std::iter::once(shape), rounds)
+            })
+            .filter(|group| !group.rounds.is_empty())
+            .collect(),
+    };
+
+    let max: usize = groups.iter().map(Group::max).sum();
+    let Some(target) = usize::try_from(target).ok().filter(|&target| target <= max) else {
+        return Ok(None);
+    };
+
+    // `after[idx]`: every total the groups from `idx` on can add up to.
+    let totals: Vec<Bits> = groups.iter().map(Group::totals).collect();
+    let mut after = vec![Bits::new(0)];
+    after[0].set(0);
+    for group in totals.iter().rev() {
+        let next = after[after.len() - 1].sumset(group);
+        after.push(next);
+    }
+    after.reverse();
+    if !after[0].get(target) {
+        return Ok(None);
+    }
+
+    let mut lines = vec![String::new(); rounds];
+    let mut left = target;
+    for (idx, group) in groups.iter().enumerate() {
+        let mut total = (0..=left)
+            .find(|&total| totals[idx].get(total) && after[idx + 1].get(left - total))
+            .expect("the target is reachable");
+        left -= total;
+        for (done, &round) in group.rounds.iter().enumerate() {
+            let remaining = group.rounds.len() - done - 1;
+            let (value, line) = group
+                .choices
+                .iter()
+                .find(|&&(value, _)| value <= total && group.feasible(total - value, remaining))
+                .expect("the group's total is reachable");
+            lines[round] = line.clone();
+            total -= value;
+        }
+    }
+    Ok(Some(lines))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve;
+
+    fn check(rules: &Rules, part: Part, guide: &[String], target: u64) {
+        let total = match part {
+            Part::One => solve::<Game>(rules, guide),
+            Part::Two => solve::<FixedGame>(rules, guide),
+        };
+        assert_eq!(u64::from(total.unwrap()), target);
+    }
+
+    #[test]
+    fn sumset_matches_brute_force() {
+        let sets: [&[usize]; 5] = [
+            &[],
+            &[0],
+            &[3, 5, 7, 9, 20],
+            &[0, 1, 2, 4, 130],
+            &[6, 12, 18, 200],
+        ];
+        for a in sets {
+            for b in sets {
+                let (mut x, mut y) = (Bits::new(210), Bits::new(70));
+                a.iter().for_each(|&idx| x.set(idx));
+                b.iter()
+                    .filter(|&&idx| idx < 70)
+                    .for_each(|&idx| y.set(idx));
+                let sums = x.sumset(&y);
+                for total in 0..sums.len() {
+                    let brute = a.iter().any(|&i| total >= i && y.get(total - i));
+                    assert_eq!(sums.get(total), brute, "{:?} + {:?} at {}", a, b, total);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hits_the_target_or_proves_it_cant() {
+        let rules = Rules::classic();
+        for part in Part::ALL {
+            // Every round scores between 1 and 9.
+            for target in 0..=30 {
+                match synthesize(rules, part, 3, None, target).unwrap() {
+                    Some(guide) => check(rules, part, &guide, target),
+                    None => assert!(!(3..=27).contains(&target), "{}", target),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_the_opponent_column() {
+        let rules = Rules::classic();
+        let rock = rules.shape("Rock").unwrap();
+        let column = [rock; 3];
+        // Against Rock a fixed round scores 3, 4 or 8.
+        let reachable = [9, 10, 11, 12, 14, 15, 16, 19, 20, 24];
+        for target in 0..30 {
+            let guide = synthesize(rules, Part::Two, 3, Some(&column), target).unwrap();
+            assert_eq!(guide.is_some(), reachable.contains(&target), "{}", target);
+            if let Some(guide) = guide {
+                assert!(guide.iter().all(|line| line.starts_with('A')));
+                check(rules, Part::Two, &guide, target);
+            }
+        }
+        assert!(synthesize(rules, Part::Two, 2, Some(&column), 8).is_err());
+    }
+
+    #[test]
+    fn mixed_column() {
+        let rules = Rules::lizard_spock();
+        let column: Vec<Shape> = rules.shapes().chain(rules.shapes()).take(4).collect();
+        // Every combination of our moves, by brute force.
+        let mut reachable = [false; 50];
+        for choice in 0..5usize.pow(4) {
+            let total: u32 = column
+                .iter()
+                .enumerate()
+                .map(|(idx, &opponent)| {
+                    let ours = rules.shapes().nth(choice / 5usize.pow(idx as u32) % 5);
+                    Game::new(opponent, ours.unwrap()).result()
+                })
+                .sum();
+            reachable[total as usize] = true;
+        }
+        for (target, &reachable) in reachable.iter().enumerate() {
+            let guide = synthesize(&rules, Part::One, 4, Some(&column), target as u64).unwrap();
+            assert_eq!(guide.is_some(), reachable, "{}", target);
+            if let Some(guide) = guide {
+                check(&rules, Part::One, &guide, target as u64);
+            }
+        }
+    }
+}
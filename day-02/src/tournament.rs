@@ -0,0 +1,204 @@
+use crate::{Game, Match, Shape};
+
+/// Something that plays rounds against an opponent, one shape at a time.
+pub trait Player<'r> {
+    fn name(&self) -> String;
+
+    fn play(&mut self) -> Shape<'r>;
+
+    /// What the opponent played in the round just finished.
+    fn observe(&mut self, opponent: Shape<'r>) {
+        let _ = opponent;
+    }
+
+    /// Forgets the last opponent before a new match.
+    fn reset(&mut self) {}
+}
+
+pub type Bot<'r> = Box<dyn Player<'r> + 'r>;
+
+/// Two players, by their index in the lineup, and their points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchResult {
+    pub first: usize,
+    pub second: usize,
+    pub scores: (u64, u64),
+}
+
+impl MatchResult {
+    /// `None` on a draw.
+    pub fn winner(&self) -> Option<usize> {
+        match self.scores.0.cmp(&self.scores.1) {
+            std::cmp::Ordering::Greater => Some(self.first),
+            std::cmp::Ordering::Less => Some(self.second),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+}
+
+/// Plays `rounds` rounds, each scored with [`Match::result`].
+pub fn play_match<'r>(
+    first: &mut dyn Player<'r>,
+    second: &mut dyn Player<'r>,
+    rounds: usize,
+) -> (u64, u64) {
+    first.reset();
+    second.reset();
+    let mut scores = (0, 0);
+    for _ in 0..rounds {
+        let (a, b) = (first.play(), second.play());
+        scores.0 += u64::from(Game::new(b, a).result());
+        scores.1 += u64::from(Game::new(a, b).result());
+        first.observe(b);
+        second.observe(a);
+    }
+    scores
+}
+
+fn play_pair(players: &mut [Bot], first: usize, second: usize, rounds: usize) -> MatchResult {
+    debug_assert!(first < second);
+    let (head, tail) = players.split_at_mut(second);
+    MatchResult {
+        first,
+        second,
+        scores: play_match(head[first].as_mut(), tail[0].as_mut(), rounds),
+    }
+}
+
+/// Every player against every other one.
+pub fn round_robin(players: &mut [Bot], rounds: usize) -> Vec<MatchResult> {
+    let mut matches = Vec::new();
+    for first in 0..players.len() {
+        for second in first + 1..players.len() {
+            matches.push(play_pair(players, first, second, rounds));
+        }
+    }
+    matches
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bracket {
+    /// The matches of each stage, first stage first.
+    pub stages: Vec<Vec<MatchResult>>,
+    pub champion: Option<usize>,
+}
+
+/// Single elimination, seeded in lineup order: the best remaining seed meets
+/// the worst. A draw goes to the better seed and the middle seed of an odd
+/// field gets a bye.
+pub fn elimination(players: &mut [Bot], rounds: usize) -> Bracket {
+    let mut remaining: Vec<usize> = (0..players.len()).collect();
+    let mut stages = Vec::new();
+    while remaining.len() > 1 {
+        let mut matches = Vec::new();
+        let mut next = Vec::new();
+        for idx in 0..remaining.len() / 2 {
+            let (first, second) = (remaining[idx], remaining[remaining.len() - 1 - idx]);
+            let result = play_pair(players, first, second, rounds);
+            next.push(result.winner().unwrap_or(first));
+            matches.push(result);
+        }
+        if remaining.len() % 2 == 1 {
+            next.push(remaining[remaining.len() / 2]);
+        }
+        next.sort_unstable();
+        remaining = next;
+        stages.push(matches);
+    }
+    Bracket {
+        stages,
+        champion: remaining.first().copied(),
+    }
+}
+
+/// How one player did over a set of matches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing {
+    pub player: usize,
+    pub name: String,
+    pub points: u64,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+}
+
+/// Standings, most points first; more wins and then the better seed break
+/// ties.
+pub fn leaderboard(players: &[Bot], matches: &[MatchResult]) -> Vec<Standing> {
+    let mut standings: Vec<Standing> = players
+        .iter()
+        .enumerate()
+        .map(|(player, bot)| Standing {
+            player,
+            name: bot.name(),
+            points: 0,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+        })
+        .collect();
+
+    for result in matches {
+        for (player, points) in [
+            (result.first, result.scores.0),
+            (result.second, result.scores.1),
+        ] {
+            let standing = &mut standings[player];
+            standing.points += points;
+            match result.winner() {
+                None => standing.draws += 1,
+                Some(winner) if winner == player => standing.wins += 1,
+                Some(_) => standing.losses += 1,
+            }
+        }
+    }
+
+    standings.sort_by_key(|standing| {
+        (
+            std::cmp::Reverse(standing.points),
+            std::cmp::Reverse(standing.wins),
+            standing.player,
+        )
+    });
+    standings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bots::{Constant, Cycle, Frequency},
+        Rules,
+    };
+
+    #[test]
+    fn a_counter_beats_a_constant() {
+        let rules = Rules::classic();
+        let rock = rules.shape("Rock").unwrap();
+        let mut constant = Constant::new(rock);
+        let mut frequency = Frequency::new(rules);
+        // Paper, the answer to no history, beats Rock every round.
+        assert_eq!(play_match(&mut constant, &mut frequency, 10), (10, 80));
+    }
+
+    #[test]
+    fn brackets() {
+        let rules = Rules::classic();
+        let mut players: Vec<Bot> = vec![
+            Box::new(Constant::new(rules.shape("Rock").unwrap())),
+            Box::new(Cycle::new(rules)),
+            Box::new(Frequency::new(rules)),
+        ];
+
+        let matches = round_robin(&mut players, 100);
+        assert_eq!(matches.len(), 3);
+        let standings = leaderboard(&players, &matches);
+        assert_eq!(standings[0].name, "frequency");
+        assert_eq!(standings[0].wins + standings[0].draws, 2);
+
+        let bracket = elimination(&mut players, 100);
+        assert_eq!(bracket.stages.len(), 2);
+        assert_eq!(bracket.stages[0].len(), 1);
+        assert_eq!(bracket.champion, Some(2));
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{item, Priority, Rucksack};
+
+/// What one rucksack looks like on inspection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub line: usize,
+    /// Every item type in both compartments; the puzzle expects exactly one.
+    pub misplaced: Rucksack,
+    /// Length in bytes, when it can't be split evenly into compartments.
+    /// Bytes, like [`Rucksack::compartments`] splits, so a stray wider than
+    /// one byte counts as it does there.
+    pub odd_length: Option<usize>,
+    /// Anything that isn't an item, with its 1-based column.
+    pub strays: Vec<(usize, char)>,
+}
+
+impl Report {
+    pub fn is_clean(&self) -> bool {
+        self.misplaced.len() == 1 && self.odd_length.is_none() && self.strays.is_empty()
+    }
+}
+
+/// Reports on every rucksack of an inventory.
+#[derive(Debug, Clone)]
+pub struct Audit {
+    pub reports: Vec<Report>,
+    /// `counts[priority]`: rucksacks that item type is misplaced in.
+    counts: Vec<usize>,
+}
+
+impl Audit {
+    /// The `n` item types misplaced in the most rucksacks, with how many;
+    /// the lowest priority first on a tie. Types never misplaced are left
+    /// out.
+    pub fn most_misplaced(&self, n: usize) -> Vec<(char, usize)> {
+        let mut counts: Vec<(char, usize)> = (1..=52)
+            .filter(|&priority| self.counts[priority as usize] > 0)
+            .map(|priority| {
+                let item = item(priority).expect("priorities are items");
+                (item, self.counts[priority as usize])
+            })
+            .collect();
+        counts.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        counts.truncate(n);
+        counts
+    }
+}
+
+/// Inspects each line as a rucksack, without giving up on anything that
+/// doesn't parse.
+pub fn audit(backpacks: &[String]) -> Audit {
+    let mut counts = vec![0; 53];
+    let reports = backpacks
+        .iter()
+        .enumerate()
+        .map(|(idx, backpack)| {
+            let (compartment_1, compartment_2) = Rucksack::compartments(backpack);
+            let misplaced = compartment_1 & compartment_2;
+            for priority in misplaced.priorities() {
+                counts[priority as usize] += 1;
+            }
+            let length = backpack.len();
+            Report {
+                line: idx + 1,
+                misplaced,
+                odd_length: (!length.is_multiple_of(2)).then_some(length),
+                strays: backpack
+                    .chars()
+                    .enumerate()
+                    .filter(|(_, item)| item.get_priority().is_none())
+                    .map(|(column, item)| (column + 1, item))
+                    .collect(),
+            }
+        })
+        .collect();
+    Audit { reports, counts }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_every_problem() {
+        let backpacks = ["abcb", "abcab", "a1Ba", "aAbB", "xyzxyz", "aéa"].map(String::from);
+        let audit = audit(&backpacks);
+        let clean: Vec<bool> = audit.reports.iter().map(Report::is_clean).collect();
+        assert_eq!(clean, [true, false, false, false, false, false]);
+
+        let [one, odd, stray, none, many, wide] = &audit.reports[..] else {
+            panic!("{:?}", audit.reports)
+        };
+        assert_eq!(one.misplaced.items().collect::<String>(), "b");
+        assert_eq!((odd.line, odd.odd_length), (2, Some(5)));
+        assert_eq!(stray.strays, [(2, '1')]);
+        assert!(none.misplaced.is_empty());
+        assert_eq!(many.misplaced.len(), 3);
+        // Four bytes, split after the first byte of the é.
+        assert_eq!(
+            (wide.odd_length, wide.strays.clone()),
+            (None, vec![(2, 'é')])
+        );
+        assert_eq!(wide.misplaced.items().collect::<String>(), "a");
+
+        assert_eq!(audit.most_misplaced(3), [('a', 3), ('b', 2), ('x', 1)]);
+    }
+}
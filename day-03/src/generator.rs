@@ -0,0 +1,48 @@
+use std::io::{self, Write};
+
+use aoc_common::{Generator, Rng};
+
+use crate::{RucksackReorganization, ELF_GROUP_SIZE};
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A rucksack whose compartments share only the first item of `pool`, with
+/// `badge` packed into one of them.
+fn rucksack(rng: &mut Rng, badge: u8, pool: &[u8]) -> String {
+    let (dupe, rest) = pool.split_first().unwrap();
+    let (left_items, right_items) = rest.split_at(rest.len() / 2);
+    let half = rng.between(2, 16) as usize;
+
+    let mut left: Vec<u8> = (0..half).map(|_| *rng.pick(left_items)).collect();
+    let mut right: Vec<u8> = (0..half).map(|_| *rng.pick(right_items)).collect();
+    left[0] = *dupe;
+    right[0] = *dupe;
+    if rng.chance(0.5) {
+        left[1] = badge;
+    } else {
+        right[1] = badge;
+    }
+    rng.shuffle(&mut left);
+    rng.shuffle(&mut right);
+
+    left.append(&mut right);
+    String::from_utf8(left).unwrap()
+}
+
+/// `size` is the number of rucksacks, rounded up to whole groups. Every elf
+/// in a group draws from its own pool of items, so the badge is the only
+/// item the whole group shares.
+impl Generator for RucksackReorganization {
+    fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+        for _ in 0..size.div_ceil(ELF_GROUP_SIZE) {
+            let mut items = ITEMS.to_vec();
+            rng.shuffle(&mut items);
+            let badge = items.pop().unwrap();
+            let pool_size = items.len() / ELF_GROUP_SIZE;
+            for pool in items.chunks(pool_size).take(ELF_GROUP_SIZE) {
+                writeln!(out, "{}", rucksack(rng, badge, pool))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,331 @@
+use std::io::{BufRead, BufReader, Read};
+
+use aoc_common::{input, Day, Diagnostic, Error, Result, Solution};
+
+pub mod audit;
+mod generator;
+#[cfg(test)]
+mod naive;
+mod rucksack;
+
+pub use rucksack::Rucksack;
+
+pub const DAY: Day = Day::new::<RucksackReorganization>(env!("CARGO_MANIFEST_DIR"))
+    .with_generator::<RucksackReorganization>();
+
+pub struct RucksackReorganization;
+
+/// Elves per group in part two.
+pub const ELF_GROUP_SIZE: usize = 3;
+
+pub trait Priority {
+    /// `None` for anything that isn't an item.
+    fn get_priority(&self) -> Option<u32>;
+}
+
+/// The ASCII fast path [`Rucksack`] reads items with.
+impl Priority for u8 {
+    fn get_priority(&self) -> Option<u32> {
+        match self {
+            b'a'..=b'z' => Some(u32::from(self - b'a') + 1),
+            b'A'..=b'Z' => Some(u32::from(self - b'A') + 27),
+            _ => None,
+        }
+    }
+}
+
+impl Priority for char {
+    fn get_priority(&self) -> Option<u32> {
+        u8::try_from(*self).ok()?.get_priority()
+    }
+}
+
+/// The item with `priority`, the inverse of [`Priority::get_priority`].
+pub fn item(priority: u32) -> Option<char> {
+    let offset = u8::try_from(priority).ok()?;
+    match offset {
+        1..=26 => Some(char::from(b'a' + offset - 1)),
+        27..=52 => Some(char::from(b'A' + offset - 27)),
+        _ => None,
+    }
+}
+
+/// The first thing in `backpack` that isn't an item.
+fn check_items(backpack: &str) -> std::result::Result<(), Diagnostic> {
+    match backpack
+        .char_indices()
+        .find(|(_, item)| item.get_priority().is_none())
+    {
+        Some((idx, item)) => Err(Diagnostic::unexpected(
+            backpack,
+            &backpack[idx..idx + item.len_utf8()],
+            "an item, a-z or A-Z",
+        )),
+        None => Ok(()),
+    }
+}
+
+fn load_data<R: Read>(reader: R) -> Result<Vec<String>> {
+    let backpacks = input::lines(reader)?;
+    for (idx, backpack) in backpacks.iter().enumerate() {
+        check_items(backpack).map_err(|err| err.at_line(idx + 1))?;
+    }
+    Ok(backpacks)
+}
+
+/// Priority of the item in both of the rucksack's compartments.
+fn misplaced(backpack: &str, line: usize) -> Result<u32> {
+    let (compartment_1, compartment_2) = Rucksack::compartments(backpack);
+    let dupes = compartment_1 & compartment_2;
+    dupes.lowest().ok_or_else(|| {
+        Error::from(
+            Diagnostic::token(backpack, backpack, "no item is in both compartments").at_line(line),
+        )
+    })
+}
+
+/// Priority of the one item every rucksack of a group holds. `line` is the
+/// group's first. A group short of `group_size` rucksacks can only be the
+/// last one, and is an error rather than a smaller group.
+fn badge(elf_group: &[String], group_size: usize, line: usize) -> Result<u32> {
+    let first = &elf_group[0];
+    let error =
+        |message: String| Error::from(Diagnostic::token(first, first, message).at_line(line));
+    if elf_group.len() < group_size {
+        return Err(error(format!(
+            "the last group has {} of {} rucksacks",
+            elf_group.len(),
+            group_size
+        )));
+    }
+
+    let badges = Rucksack::common(elf_group.iter().map(|backpack| Rucksack::new(backpack)));
+    match badges.len() {
+        0 => Err(error("the group has no common badge".to_string())),
+        1 => Ok(badges.lowest().expect("there is one badge")),
+        candidates => Err(error(format!(
+            "the group has {} candidate badges: {}",
+            candidates,
+            badges
+                .items()
+                .map(String::from)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))),
+    }
+}
+
+fn no_groups() -> Error {
+    Error::invalid("groups need at least one rucksack")
+}
+
+fn solve_1(backpacks: &[String]) -> Result<u64> {
+    let mut prio_sum = 0;
+    for (idx, backpack) in backpacks.iter().enumerate() {
+        prio_sum += u64::from(misplaced(backpack, idx + 1)?);
+    }
+    Ok(prio_sum)
+}
+
+/// Sum of the badges of every group of `group_size` elves.
+pub fn badge_sum(backpacks: &[String], group_size: usize) -> Result<u64> {
+    if group_size == 0 {
+        return Err(no_groups());
+    }
+    let mut prio_sum = 0;
+    for (idx, elf_group) in backpacks.chunks(group_size).enumerate() {
+        prio_sum += u64::from(badge(elf_group, group_size, idx * group_size + 1)?);
+    }
+    Ok(prio_sum)
+}
+
+fn solve_2(backpacks: &[String]) -> Result<u64> {
+    badge_sum(backpacks, ELF_GROUP_SIZE)
+}
+
+/// Reads rucksacks straight from `reader`, one line at a time, and sums both
+/// parts' priorities, with groups of `group_size` elves. Only the current
+/// group is held in memory, so the input can be any size.
+pub fn stream_sums<R: Read>(reader: R, group_size: usize) -> Result<(u64, u64)> {
+    if group_size == 0 {
+        return Err(no_groups());
+    }
+    let mut reader = BufReader::new(reader);
+    let mut group = vec![String::new(); group_size];
+    let mut sums = (0, 0);
+    let mut number = 0;
+
+    loop {
+        let backpack = &mut group[number % group_size];
+        backpack.clear();
+        if reader.read_line(backpack)? == 0 {
+            break;
+        }
+        backpack.truncate(backpack.trim_end_matches(['\n', '\r']).len());
+        number += 1;
+        check_items(backpack).map_err(|err| err.at_line(number))?;
+
+        sums.0 += u64::from(misplaced(backpack, number)?);
+        if number.is_multiple_of(group_size) {
+            sums.1 += u64::from(badge(&group, group_size, number - group_size + 1)?);
+        }
+    }
+    let rest = number % group_size;
+    if rest > 0 {
+        badge(&group[..rest], group_size, number - rest + 1)?;
+    }
+    Ok(sums)
+}
+
+impl Solution for RucksackReorganization {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
+
+    type Input = Vec<String>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse<R: Read>(reader: R) -> Result<Self::Input> {
+        load_data(reader)
+    }
+
+    fn part_one(backpacks: &Self::Input) -> Result<u64> {
+        solve_1(backpacks)
+    }
+
+    fn part_two(backpacks: &Self::Input) -> Result<u64> {
+        solve_2(backpacks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_common::differential::{assert_agree, proptest::prelude::*};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../data/example");
+
+    #[test]
+    fn part_one_example() {
+        let input = RucksackReorganization::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(RucksackReorganization::part_one(&input).unwrap(), 157);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = RucksackReorganization::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(RucksackReorganization::part_two(&input).unwrap(), 70);
+    }
+
+    fn sorted_priorities(items: HashSet<char>) -> Vec<u32> {
+        let mut priorities: Vec<u32> = items
+            .iter()
+            .map(|item| item.get_priority().unwrap())
+            .collect();
+        priorities.sort_unstable();
+        priorities
+    }
+
+    #[test]
+    fn streaming_matches_parts() {
+        let input = RucksackReorganization::parse(EXAMPLE.as_bytes()).unwrap();
+        let parts = (solve_1(&input).unwrap(), solve_2(&input).unwrap());
+        assert_eq!(
+            stream_sums(EXAMPLE.as_bytes(), ELF_GROUP_SIZE).unwrap(),
+            parts
+        );
+        assert_eq!(parts, (157, 70));
+    }
+
+    #[test]
+    fn compartment_masks_match_naive() {
+        assert_agree(
+            "[a-zA-Z]{0,24}",
+            |backpack| sorted_priorities(naive::compartment_dupes(backpack)),
+            |backpack| {
+                let (compartment_1, compartment_2) = Rucksack::compartments(backpack);
+                (compartment_1 & compartment_2).priorities().collect()
+            },
+        );
+    }
+
+    #[test]
+    fn badge_masks_match_naive() {
+        assert_agree(
+            prop::collection::vec("[a-zA-Z]{0,24}", ELF_GROUP_SIZE),
+            |group| sorted_priorities(naive::group_badges(group)),
+            |group| {
+                Rucksack::common(group.iter().map(|backpack| Rucksack::new(backpack)))
+                    .priorities()
+                    .collect()
+            },
+        );
+    }
+
+    #[test]
+    fn byte_and_char_priorities_agree() {
+        for byte in 0..=u8::MAX {
+            assert_eq!(byte.get_priority(), char::from(byte).get_priority());
+        }
+        assert_eq!(Rucksack::new("a\u{0}é-Z"), Rucksack::new("aZ"));
+    }
+
+    #[test]
+    fn rejects_strays() {
+        let err = RucksackReorganization::parse("abAB\nab1B\n".as_bytes()).unwrap_err();
+        let Error::Parse(err) = err else {
+            panic!("{:?}", err)
+        };
+        assert_eq!((err.line, err.column(), err.text()), (2, 3, "1"));
+        assert!(stream_sums("abAB\naébB\n".as_bytes(), 1).is_err());
+        assert_eq!(item(52), Some('Z'));
+        assert_eq!(item(0).or(item(53)), None);
+    }
+
+    fn error(result: Result<u64>) -> String {
+        result.unwrap_err().to_string()
+    }
+
+    #[test]
+    fn bad_groups() {
+        let input = RucksackReorganization::parse(EXAMPLE.as_bytes()).unwrap();
+        let whole = error(badge_sum(&input, 6));
+        assert!(whole.contains("line 1") && whole.contains("no common badge"));
+        let pairs = error(badge_sum(&input, 2));
+        assert!(pairs.contains("line 1") && pairs.contains("5 candidate badges: f, r, s, F, M"));
+        let short = error(badge_sum(&input[..5], ELF_GROUP_SIZE));
+        assert!(short.contains("line 4") && short.contains("the last group has 2 of 3"));
+        let five_lines = input[..5].join("\n");
+        let stream = stream_sums(five_lines.as_bytes(), ELF_GROUP_SIZE).unwrap_err();
+        assert!(stream.to_string().contains("line 4"));
+        assert!(badge_sum(&input, 0).is_err());
+    }
+
+    #[test]
+    fn any_group_size() {
+        let groups =
+            (1..=6usize).prop_flat_map(|size| prop::collection::vec("[a-zA-Z]{1,24}", size));
+        assert_agree(
+            groups,
+            |group| {
+                (1..=52)
+                    .filter(|&priority| {
+                        group.iter().all(|backpack| {
+                            backpack
+                                .chars()
+                                .any(|item| item.get_priority() == Some(priority))
+                        })
+                    })
+                    .collect::<Vec<u32>>()
+            },
+            |group| {
+                Rucksack::common(group.iter().map(|backpack| Rucksack::new(backpack)))
+                    .priorities()
+                    .collect()
+            },
+        );
+    }
+}
@@ -1,95 +1,113 @@
-use std::{
-    collections::{hash_map::RandomState, hash_set::Intersection, HashSet},
-    fs::File,
-    hash::Hash,
-    io::{BufRead, BufReader},
-    path::Path,
-};
+use std::{path::PathBuf, process::ExitCode};
 
-const INPUT_DATA: &'static str = "./data/input";
-const ELF_GROUP_SIZE: usize = 3;
+use aoc_common::{
+    day::{self, print_answer},
+    input, Part, Result,
+};
+use clap::{Parser, Subcommand};
+use day_03::{
+    audit::{self, Report},
+    DAY, ELF_GROUP_SIZE,
+};
 
-pub trait Priority {
-    fn get_priority(&self) -> u32;
+#[derive(Parser)]
+#[command(name = "day-03", about = "Rucksack Reorganization")]
+#[command(args_conflicts_with_subcommands = true)]
+struct Cli {
+    /// Puzzle input, `-` for stdin; defaults to data/input
+    input: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Option<Command>,
 }
 
-impl Priority for char {
-    fn get_priority(&self) -> u32 {
-        if !self.is_alphabetic() {
-            panic!("Wrong char");
-        }
-        let code = *self as u32;
-
-        if self.is_lowercase() {
-            return code - 96;
-        }
-        code - 38
-    }
+#[derive(Subcommand)]
+enum Command {
+    /// List, per rucksack, what's in both compartments and anything else
+    /// off about it, then the item types misplaced most often
+    Audit {
+        /// Puzzle input, `-` for stdin; defaults to data/input
+        input: Option<PathBuf>,
+        /// Number of item types in the summary
+        #[arg(long, default_value_t = 5)]
+        top: usize,
+    },
+    /// Read the input once, line by line, and print both parts
+    Stream {
+        /// Puzzle input, `-` for stdin; defaults to data/input
+        input: Option<PathBuf>,
+        /// Elves per badge group
+        #[arg(short, long, default_value_t = ELF_GROUP_SIZE)]
+        group_size: usize,
+    },
 }
 
-fn load_data(path: &str) -> Vec<String> {
-    let file_path = Path::new(path);
-    let file = File::open(file_path).expect("Couldn't open file");
-
-    let buf = BufReader::new(file);
-    buf.lines().map(|l| l.unwrap()).collect()
+fn stream(path: PathBuf, group_size: usize) -> Result<()> {
+    let (misplaced, badges) =
+        day_03::stream_sums(input::open(&path)?, group_size).map_err(|err| err.in_file(&path))?;
+    println!("misplaced items: {}", misplaced);
+    println!("badges:          {}", badges);
+    Ok(())
 }
 
-fn solve_1() {
-    let backpacks = load_data(INPUT_DATA);
-    let mut prio_sum: u32 = 0;
-    for backpack in backpacks {
-        let backpack_size = backpack.len();
-        let mut compartment_1: HashSet<char> = HashSet::new();
-        let mut compartment_2: HashSet<char> = HashSet::new();
+fn run_audit(path: PathBuf, top: usize) -> Result<()> {
+    let backpacks = input::lines(input::open(&path)?).map_err(|err| err.in_file(&path))?;
+    let audit = audit::audit(&backpacks);
 
-        for (idx, item) in backpack.chars().enumerate() {
-            if idx < backpack_size / 2 {
-                compartment_1.insert(item);
-            } else {
-                compartment_2.insert(item);
-            }
+    let width = backpacks.len().to_string().len();
+    for report in &audit.reports {
+        let items: Vec<String> = report.misplaced.items().map(String::from).collect();
+        let mut notes = vec![match items.is_empty() {
+            true => "nothing misplaced".to_string(),
+            false => items.join(", "),
+        }];
+        notes.extend(
+            report
+                .odd_length
+                .map(|length| format!("odd length, {} bytes", length)),
+        );
+        for (column, item) in &report.strays {
+            notes.push(format!("{:?} at column {} isn't an item", item, column));
         }
+        println!("line {:>width$}: {}", report.line, notes.join("; "));
+    }
 
-        let dupe: &char = compartment_1
-            .intersection(&compartment_2)
-            .collect::<HashSet<&char>>()
-            .drain()
-            .collect::<Vec<&char>>()[0];
-
-        prio_sum += dupe.get_priority()
+    let flagged = |check: fn(&Report) -> bool| audit.reports.iter().filter(|r| check(r)).count();
+    println!();
+    println!("rucksacks:          {}", audit.reports.len());
+    println!("clean:              {}", flagged(Report::is_clean));
+    println!(
+        "nothing misplaced:  {}",
+        flagged(|r| r.misplaced.is_empty())
+    );
+    println!("several misplaced:  {}", flagged(|r| r.misplaced.len() > 1));
+    println!(
+        "odd length:         {}",
+        flagged(|r| r.odd_length.is_some())
+    );
+    println!("with strays:        {}", flagged(|r| !r.strays.is_empty()));
+    println!("most misplaced:");
+    for (item, count) in audit.most_misplaced(top) {
+        println!("  {} {}", item, count);
     }
-    println!("{:?}", prio_sum);
+    Ok(())
 }
 
-fn solve_2() {
-    let backpacks = load_data(INPUT_DATA);
-    let mut prio_sum: u32 = 0;
-
-    for elf_group in backpacks.chunks(ELF_GROUP_SIZE) {
-        let item_sets: Vec<HashSet<char>> = elf_group
-            .iter()
-            .map(|backpack| HashSet::<char>::from_iter(backpack.chars()))
-            .collect();
-        let intersections: Vec<HashSet<&char>> = item_sets
-            .windows(2)
-            .map(|sets| sets[0].intersection(&sets[1]))
-            .collect::<Vec<Intersection<char, RandomState>>>()
-            .iter()
-            .map(|intersection| intersection.to_owned().collect::<HashSet<&char>>())
-            .collect();
-        let badge: &&char = intersections[0]
-            .intersection(&intersections[1])
-            .collect::<HashSet<&&char>>()
-            .drain()
-            .collect::<Vec<&&char>>()[0];
-
-        prio_sum += badge.get_priority();
+fn run(cli: Cli) -> Result<()> {
+    match cli.command {
+        None => DAY.run(
+            &Part::ALL,
+            cli.input.unwrap_or_else(|| DAY.default_input()),
+            print_answer,
+        ),
+        Some(Command::Audit { input, top }) => {
+            run_audit(input.unwrap_or_else(|| DAY.default_input()), top)
+        }
+        Some(Command::Stream { input, group_size }) => {
+            stream(input.unwrap_or_else(|| DAY.default_input()), group_size)
+        }
     }
-    println!("{:?}", prio_sum);
 }
 
-fn main() {
-    solve_1();
-    solve_2();
+fn main() -> ExitCode {
+    day::exit(run(Cli::parse()))
 }
@@ -0,0 +1,40 @@
+//! The original solver, kept as the reference for differential tests.
+
+use std::collections::{hash_map::RandomState, hash_set::Intersection, HashSet};
+
+pub fn compartment_dupes(backpack: &str) -> HashSet<char> {
+    let backpack_size = backpack.len();
+    let mut compartment_1: HashSet<char> = HashSet::new();
+    let mut compartment_2: HashSet<char> = HashSet::new();
+
+    for (idx, item) in backpack.chars().enumerate() {
+        if idx < backpack_size / 2 {
+            compartment_1.insert(item);
+        } else {
+            compartment_2.insert(item);
+        }
+    }
+
+    compartment_1
+        .intersection(&compartment_2)
+        .copied()
+        .collect()
+}
+
+pub fn group_badges(elf_group: &[String]) -> HashSet<char> {
+    let item_sets: Vec<HashSet<char>> = elf_group
+        .iter()
+        .map(|backpack| HashSet::<char>::from_iter(backpack.chars()))
+        .collect();
+    let intersections: Vec<HashSet<&char>> = item_sets
+        .windows(2)
+        .map(|sets| sets[0].intersection(&sets[1]))
+        .collect::<Vec<Intersection<char, RandomState>>>()
+        .iter()
+        .map(|intersection| intersection.to_owned().collect::<HashSet<&char>>())
+        .collect();
+    intersections[0]
+        .intersection(&intersections[1])
+        .map(|badge| **badge)
+        .collect()
+}
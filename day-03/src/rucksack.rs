@@ -0,0 +1,110 @@
+use std::ops::{BitAnd, BitOr};
+
+use crate::{item, Priority};
+
+/// The item types in a rucksack, one bit per priority.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Rucksack(u64);
+
+impl Rucksack {
+    pub const EMPTY: Rucksack = Rucksack(0);
+    /// Every item type, priorities 1 to 52.
+    pub const ALL: Rucksack = Rucksack(((1 << 52) - 1) << 1);
+
+    /// Anything that isn't an item is left out.
+    pub fn new(items: &str) -> Rucksack {
+        Rucksack::from_bytes(items.as_bytes())
+    }
+
+    fn from_bytes(items: &[u8]) -> Rucksack {
+        Rucksack(items.iter().fold(0, |mask, item| {
+            mask | item.get_priority().map_or(0, |priority| 1 << priority)
+        }))
+    }
+
+    /// The two halves of a rucksack, split by bytes.
+    pub fn compartments(items: &str) -> (Rucksack, Rucksack) {
+        let (first, second) = items.as_bytes().split_at(items.len() / 2);
+        (Rucksack::from_bytes(first), Rucksack::from_bytes(second))
+    }
+
+    /// Item types in every one of `rucksacks`; [`Rucksack::ALL`] if there
+    /// are none.
+    pub fn common(rucksacks: impl IntoIterator<Item = Rucksack>) -> Rucksack {
+        rucksacks
+            .into_iter()
+            .fold(Rucksack::ALL, Rucksack::intersection)
+    }
+
+    pub fn intersection(self, other: Rucksack) -> Rucksack {
+        Rucksack(self.0 & other.0)
+    }
+
+    pub fn union(self, other: Rucksack) -> Rucksack {
+        Rucksack(self.0 | other.0)
+    }
+
+    pub fn contains(self, priority: u32) -> bool {
+        priority < 64 && self.0 >> priority & 1 == 1
+    }
+
+    /// Number of item types.
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Priority of the lowest item type; it's the bit's index.
+    pub fn lowest(self) -> Option<u32> {
+        (!self.is_empty()).then(|| self.0.trailing_zeros())
+    }
+
+    /// Priorities of the item types, lowest first.
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        (1..=52).filter(move |&priority| self.contains(priority))
+    }
+
+    /// The item types, lowest priority first.
+    pub fn items(self) -> impl Iterator<Item = char> {
+        self.priorities()
+            .map(|priority| item(priority).expect("priorities are items"))
+    }
+}
+
+impl BitAnd for Rucksack {
+    type Output = Rucksack;
+
+    fn bitand(self, other: Rucksack) -> Rucksack {
+        self.intersection(other)
+    }
+}
+
+impl BitOr for Rucksack {
+    type Output = Rucksack;
+
+    fn bitor(self, other: Rucksack) -> Rucksack {
+        self.union(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_operations() {
+        let (first, second) = Rucksack::compartments("vJrwpWtwJgWrhcsFMMfFFhFp");
+        assert_eq!((first & second).priorities().collect::<Vec<_>>(), [16]);
+        assert_eq!((first | second).len(), 14);
+        assert_eq!(
+            Rucksack::new("aZ").priorities().collect::<Vec<_>>(),
+            [1, 52]
+        );
+        assert_eq!(Rucksack::ALL.len(), 52);
+        assert_eq!(Rucksack::common([]), Rucksack::ALL);
+        assert_eq!(Rucksack::EMPTY.lowest(), None);
+    }
+}
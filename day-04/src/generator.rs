@@ -0,0 +1,26 @@
+use std::io::{self, Write};
+
+use aoc_common::{Generator, Rng};
+
+use crate::CampCleanup;
+
+fn section(rng: &mut Rng) -> (u64, u64) {
+    let start = rng.between(1, 99);
+    (start, rng.between(start, 99))
+}
+
+/// `size` is the number of pairs.
+impl Generator for CampCleanup {
+    fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+        for _ in 0..size {
+            let (first_start, first_end) = section(rng);
+            let (second_start, second_end) = section(rng);
+            writeln!(
+                out,
+                "{}-{},{}-{}",
+                first_start, first_end, second_start, second_end
+            )?;
+        }
+        Ok(())
+    }
+}
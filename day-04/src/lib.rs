@@ -0,0 +1,139 @@
+use std::{io::Read, str::FromStr};
+
+use aoc_common::{input, Day, Diagnostic, Result, Solution};
+
+mod generator;
+
+pub const DAY: Day =
+    Day::new::<CampCleanup>(env!("CARGO_MANIFEST_DIR")).with_generator::<CampCleanup>();
+
+pub struct CampCleanup;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Assignment {
+    start: u32,
+    end: u32,
+}
+
+fn section(range: &str, value: &str) -> std::result::Result<u32, Diagnostic> {
+    value
+        .parse()
+        .map_err(|_| Diagnostic::unexpected(range, value, "a section number"))
+}
+
+impl FromStr for Assignment {
+    type Err = Diagnostic;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        let (start, end) = value
+            .split_once('-')
+            .ok_or_else(|| Diagnostic::unexpected(value, value, "a range like 2-4"))?;
+        Ok(Assignment {
+            start: section(value, start)?,
+            end: section(value, end)?,
+        })
+    }
+}
+
+/// Both elves' assignments from a line like `2-4,6-8`.
+fn parse_pair(line: &str) -> std::result::Result<(Assignment, Assignment), Diagnostic> {
+    let (first, second) = line
+        .split_once(',')
+        .ok_or_else(|| Diagnostic::unexpected(line, line, "two ranges like 2-4,6-8"))?;
+    let parse = |part: &str| {
+        part.parse::<Assignment>()
+            .map_err(|err| err.within(line, part))
+    };
+    Ok((parse(first)?, parse(second)?))
+}
+
+trait Overlap<T> {
+    fn overlaps_fully(&self, other: T) -> bool;
+    fn overlaps(&self, other: T) -> bool;
+}
+
+impl Overlap<Assignment> for Assignment {
+    fn overlaps_fully(&self, other: Assignment) -> bool {
+        (self.start <= other.start && self.end >= other.end)
+            || (other.start <= self.start && other.end >= self.end)
+    }
+    fn overlaps(&self, other: Assignment) -> bool {
+        !(self.end < other.start || self.start > other.end)
+    }
+}
+
+fn count_pairs(
+    assignments: &[(Assignment, Assignment)],
+    check: impl Fn(Assignment, Assignment) -> bool,
+) -> u32 {
+    assignments
+        .iter()
+        .filter(|&&(first, second)| check(first, second))
+        .count() as u32
+}
+
+fn load_data<R: Read>(reader: R) -> Result<Vec<(Assignment, Assignment)>> {
+    input::lines(reader)?
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| parse_pair(line).map_err(|err| err.at_line(idx + 1).into()))
+        .collect()
+}
+
+fn solve_1(assignments: &[(Assignment, Assignment)]) -> u32 {
+    count_pairs(assignments, |first, second| first.overlaps_fully(second))
+}
+
+fn solve_2(assignments: &[(Assignment, Assignment)]) -> u32 {
+    count_pairs(assignments, |first, second| first.overlaps(second))
+}
+
+impl Solution for CampCleanup {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+
+    type Input = Vec<(Assignment, Assignment)>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse<R: Read>(reader: R) -> Result<Self::Input> {
+        load_data(reader)
+    }
+
+    fn part_one(assignments: &Self::Input) -> Result<u32> {
+        Ok(solve_1(assignments))
+    }
+
+    fn part_two(assignments: &Self::Input) -> Result<u32> {
+        Ok(solve_2(assignments))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Error;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../data/example");
+
+    #[test]
+    fn part_one_example() {
+        let input = CampCleanup::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(CampCleanup::part_one(&input).unwrap(), 2);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = CampCleanup::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(CampCleanup::part_two(&input).unwrap(), 4);
+    }
+
+    #[test]
+    fn bad_section_is_reported() {
+        let Err(Error::Parse(err)) = CampCleanup::parse("2-4,6-8\n2-x,1-2\n".as_bytes()) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.line, err.column(), err.text()), (2, 3, "x"));
+    }
+}
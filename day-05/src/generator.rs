@@ -0,0 +1,53 @@
+use std::io::{self, Write};
+
+use aoc_common::{Generator, Rng};
+
+use crate::SupplyStacks;
+
+const MAX_STACKS: usize = 999;
+const MAX_GRAB: u64 = 20;
+
+/// `size` is the number of moves. The drawing starts with the usual nine
+/// stacks and gets one more per thousand moves. Moves never take more crates
+/// than a stack holds.
+impl Generator for SupplyStacks {
+    fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+        let stacks = (9 + size / 1000).min(MAX_STACKS);
+        let mut heights: Vec<u64> = (0..stacks).map(|_| rng.between(1, 12)).collect();
+        let drawing_heights = heights.clone();
+
+        let tallest = *drawing_heights.iter().max().unwrap();
+        for level in (0..tallest).rev() {
+            let row: Vec<String> = drawing_heights
+                .iter()
+                .map(|height| {
+                    if *height > level {
+                        format!("[{}]", (b'A' + rng.between(0, 25) as u8) as char)
+                    } else {
+                        "   ".to_string()
+                    }
+                })
+                .collect();
+            writeln!(out, "{}", row.join(" "))?;
+        }
+        let labels: Vec<String> = (1..=stacks).map(|label| format!("{:^3}", label)).collect();
+        writeln!(out, "{}", labels.join(" "))?;
+        writeln!(out)?;
+
+        for _ in 0..size {
+            let mut from = rng.index(stacks);
+            while heights[from] == 0 {
+                from = rng.index(stacks);
+            }
+            let mut to = rng.index(stacks - 1);
+            if to >= from {
+                to += 1;
+            }
+            let amount = rng.between(1, heights[from].min(MAX_GRAB));
+            heights[from] -= amount;
+            heights[to] += amount;
+            writeln!(out, "move {} from {} to {}", amount, from + 1, to + 1)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,264 @@
+use std::{collections::VecDeque, io::Read, str::FromStr};
+
+use aoc_common::{input, Day, Diagnostic, Error, Solution, Tokens};
+
+mod generator;
+
+pub const DAY: Day =
+    Day::new::<SupplyStacks>(env!("CARGO_MANIFEST_DIR")).with_generator::<SupplyStacks>();
+
+pub struct SupplyStacks;
+
+#[derive(Debug)]
+pub struct CraneInstructions {
+    amount: u32,
+    from: usize,
+    to: usize,
+}
+
+/// Stack numbers start at 1 in the input.
+fn stack(words: &mut Tokens, line: &str) -> Result<usize, Diagnostic> {
+    let number = words.next("a stack number")?;
+    number
+        .parse::<usize>()
+        .ok()
+        .and_then(|number| number.checked_sub(1))
+        .ok_or_else(|| Diagnostic::unexpected(line, number, "a stack number from 1"))
+}
+
+impl FromStr for CraneInstructions {
+    type Err = Diagnostic;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut words = Tokens::new(value);
+        words.keyword("move")?;
+        let amount = words.parse("an amount of crates")?;
+        words.keyword("from")?;
+        let from = stack(&mut words, value)?;
+        words.keyword("to")?;
+        let to = stack(&mut words, value)?;
+        words.end()?;
+
+        Ok(CraneInstructions { amount, from, to })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Ship {
+    crate_stacks: VecDeque<Vec<String>>,
+}
+
+trait Crane {
+    fn move_crate(&mut self, instruction: &CraneInstructions);
+    fn move_crate2(&mut self, instruction: &CraneInstructions);
+
+    fn move_crane(&mut self, instructions: &VecDeque<CraneInstructions>);
+    fn move_crane2(&mut self, instructions: &VecDeque<CraneInstructions>);
+
+    fn grab_tops(&mut self) -> Vec<String>;
+}
+
+impl Crane for Ship {
+    fn move_crate(&mut self, instruction: &CraneInstructions) {
+        let mut grip: Vec<String> = Vec::new();
+        for _ in 0..instruction.amount {
+            grip.push(self.crate_stacks[instruction.from].pop().unwrap())
+        }
+        self.crate_stacks[instruction.to].append(&mut grip);
+    }
+
+    fn move_crate2(&mut self, instruction: &CraneInstructions) {
+        let mut grip: Vec<String> = Vec::new();
+        for _ in 0..instruction.amount {
+            grip.push(self.crate_stacks[instruction.from].pop().unwrap())
+        }
+        grip.reverse();
+        self.crate_stacks[instruction.to].append(&mut grip);
+    }
+
+    fn move_crane(&mut self, instructions: &VecDeque<CraneInstructions>) {
+        instructions
+            .iter()
+            .map(|v| self.move_crate(v))
+            .for_each(drop);
+    }
+
+    fn move_crane2(&mut self, instructions: &VecDeque<CraneInstructions>) {
+        instructions
+            .iter()
+            .map(|v| self.move_crate2(v))
+            .for_each(drop);
+    }
+
+    fn grab_tops(&mut self) -> Vec<String> {
+        let tops: Vec<String> = self
+            .crate_stacks
+            .iter()
+            .map(|v| v.last().unwrap_or(&" ".to_string()).to_string())
+            .collect();
+        tops
+    }
+}
+
+fn load_data<R: Read>(reader: R) -> aoc_common::Result<Vec<String>> {
+    input::lines(reader)
+}
+
+fn build_ship(lines: &[String]) -> aoc_common::Result<(Ship, VecDeque<CraneInstructions>)> {
+    let mut iterator = lines.iter().enumerate().rev();
+    let mut instructions: VecDeque<(usize, CraneInstructions)> = VecDeque::new();
+    let mut maybe_line = iterator.next();
+    while let Some((idx, line)) = maybe_line {
+        if line.is_empty() {
+            maybe_line = iterator.next();
+            continue;
+        }
+        if !line.starts_with("move") {
+            break;
+        }
+        let instruction: CraneInstructions = line
+            .parse()
+            .map_err(|err: Diagnostic| err.at_line(idx + 1))?;
+        instructions.push_front((idx, instruction));
+        maybe_line = iterator.next();
+    }
+
+    let (numbers_idx, numbers) =
+        maybe_line.ok_or_else(|| Error::invalid("missing the line of stack numbers"))?;
+    let last_number = numbers.split_whitespace().last().unwrap_or(numbers);
+    let crate_amount: usize = last_number.parse().map_err(|_| {
+        Diagnostic::unexpected(numbers, last_number, "the number of the last stack")
+            .at_line(numbers_idx + 1)
+    })?;
+
+    if let Some((idx, _)) = instructions
+        .iter()
+        .find(|(_, instruction)| instruction.from.max(instruction.to) >= crate_amount)
+    {
+        let line = &lines[*idx];
+        return Err(Diagnostic::token(
+            line,
+            line,
+            format!("there are only {} stacks", crate_amount),
+        )
+        .at_line(idx + 1)
+        .into());
+    }
+
+    let mut crate_stacks: VecDeque<Vec<String>> = VecDeque::new();
+
+    for _ in 0..crate_amount {
+        crate_stacks.push_back(Vec::new())
+    }
+
+    // Crate names sit in every fourth column, as in `[A] [B]`.
+    for (idx, line) in iterator {
+        for (column, (start, name)) in line.char_indices().enumerate().skip(1).step_by(4) {
+            if name.is_whitespace() {
+                continue;
+            }
+            let crate_num = column / 4;
+            if crate_num >= crate_amount {
+                let span = start..start + name.len_utf8();
+                return Err(Diagnostic::new(line, span, "crate outside of the stacks")
+                    .at_line(idx + 1)
+                    .into());
+            }
+            crate_stacks[crate_num].push(name.to_string());
+        }
+    }
+
+    // Both cranes move the same number of crates, so the heights are known
+    // before either runs.
+    let mut heights: Vec<u32> = crate_stacks
+        .iter()
+        .map(|stack| stack.len() as u32)
+        .collect();
+    for (idx, instruction) in &instructions {
+        let height = heights[instruction.from];
+        if height < instruction.amount {
+            let line = &lines[*idx];
+            return Err(Diagnostic::token(
+                line,
+                line,
+                format!("stack {} has only {} crates", instruction.from + 1, height),
+            )
+            .at_line(idx + 1)
+            .into());
+        }
+        heights[instruction.from] -= instruction.amount;
+        heights[instruction.to] += instruction.amount;
+    }
+
+    let instructions = instructions
+        .into_iter()
+        .map(|(_, instruction)| instruction)
+        .collect();
+    Ok((Ship { crate_stacks }, instructions))
+}
+
+impl Solution for SupplyStacks {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Supply Stacks";
+
+    type Input = (Ship, VecDeque<CraneInstructions>);
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse<R: Read>(reader: R) -> aoc_common::Result<Self::Input> {
+        build_ship(&load_data(reader)?)
+    }
+
+    fn part_one(input: &Self::Input) -> aoc_common::Result<String> {
+        let (ship, instructions) = input;
+        let mut ship = ship.clone();
+        ship.move_crane(instructions);
+        Ok(ship.grab_tops().concat())
+    }
+
+    fn part_two(input: &Self::Input) -> aoc_common::Result<String> {
+        let (ship, instructions) = input;
+        let mut ship = ship.clone();
+        ship.move_crane2(instructions);
+        Ok(ship.grab_tops().concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../data/example");
+
+    #[test]
+    fn part_one_example() {
+        let input = SupplyStacks::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(SupplyStacks::part_one(&input).unwrap(), "CMZ");
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = SupplyStacks::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(SupplyStacks::part_two(&input).unwrap(), "MCD");
+    }
+
+    #[test]
+    fn bad_amount_is_reported() {
+        let input = EXAMPLE.replace("move 1 from 2 to 1", "move one from 2 to 1");
+        let Err(Error::Parse(err)) = SupplyStacks::parse(input.as_bytes()) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.column(), err.text()), (6, "one"));
+        assert_eq!(err.expected.as_deref(), Some("an amount of crates"));
+    }
+
+    #[test]
+    fn moving_too_many_crates_is_reported() {
+        let input = EXAMPLE.replace("move 3 from 1 to 3", "move 5 from 1 to 3");
+        let Err(Error::Parse(err)) = SupplyStacks::parse(input.as_bytes()) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(err.line, 7);
+        assert_eq!(err.message, "stack 1 has only 3 crates");
+    }
+}
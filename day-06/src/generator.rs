@@ -0,0 +1,25 @@
+use std::io::{self, Write};
+
+use aoc_common::{Generator, Rng};
+
+use crate::TuningTrouble;
+
+const MARKER_SIZE: usize = 14;
+
+/// `size` is the packet length. Everything before the last fourteen
+/// characters uses only three letters, so both markers sit at the very end
+/// and the whole packet has to be scanned.
+impl Generator for TuningTrouble {
+    fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+        let mut packet: Vec<u8> = (0..size.saturating_sub(MARKER_SIZE))
+            .map(|_| *rng.pick(b"abc"))
+            .collect();
+
+        let mut letters: Vec<u8> = (b'a'..=b'z').collect();
+        rng.shuffle(&mut letters);
+        packet.extend_from_slice(&letters[..MARKER_SIZE]);
+
+        out.write_all(&packet)?;
+        writeln!(out)
+    }
+}
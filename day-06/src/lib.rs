@@ -0,0 +1,129 @@
+use std::io::Read;
+
+use aoc_common::{input, Day, Diagnostic, Error, Result, Solution};
+
+mod generator;
+#[cfg(test)]
+mod naive;
+
+pub const DAY: Day =
+    Day::new::<TuningTrouble>(env!("CARGO_MANIFEST_DIR")).with_generator::<TuningTrouble>();
+
+pub struct TuningTrouble;
+
+fn load_data<R: Read>(reader: R) -> Result<Vec<char>> {
+    let data = input::read_string(reader)?;
+    let packet = data.trim();
+    match packet.char_indices().find(|(_, c)| !c.is_ascii()) {
+        Some((start, c)) => {
+            Err(
+                Diagnostic::new(packet, start..start + c.len_utf8(), "unexpected character")
+                    .expected("ASCII")
+                    .at_line(1)
+                    .into(),
+            )
+        }
+        None => Ok(packet.chars().collect()),
+    }
+}
+
+/// Position right after the first `size` distinct characters. The window
+/// slides one character at a time, keeping a count per character and how
+/// many of them are in the window more than once.
+fn find_start(packet: &[char], size: usize) -> Option<usize> {
+    let mut counts = [0u32; 128];
+    let mut repeated = 0;
+
+    for (idx, c) in packet.iter().enumerate() {
+        counts[*c as usize] += 1;
+        if counts[*c as usize] == 2 {
+            repeated += 1;
+        }
+        if idx >= size {
+            let old = packet[idx - size] as usize;
+            counts[old] -= 1;
+            if counts[old] == 1 {
+                repeated -= 1;
+            }
+        }
+        if idx + 1 >= size && repeated == 0 {
+            return Some(idx + 1);
+        }
+    }
+    None
+}
+
+fn marker(packet: &[char], size: usize) -> Result<usize> {
+    find_start(packet, size)
+        .ok_or_else(|| Error::invalid(format!("no {} distinct characters in a row", size)))
+}
+
+impl Solution for TuningTrouble {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
+
+    type Input = Vec<char>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse<R: Read>(reader: R) -> Result<Self::Input> {
+        load_data(reader)
+    }
+
+    fn part_one(packet: &Self::Input) -> Result<usize> {
+        marker(packet, 4)
+    }
+
+    fn part_two(packet: &Self::Input) -> Result<usize> {
+        marker(packet, 14)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::differential::{assert_agree, proptest::prelude::*};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../data/example");
+
+    #[test]
+    fn part_one_example() {
+        let input = TuningTrouble::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(TuningTrouble::part_one(&input).unwrap(), 7);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = TuningTrouble::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(TuningTrouble::part_two(&input).unwrap(), 19);
+    }
+
+    #[test]
+    fn other_examples() {
+        let examples = [
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsqv", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ];
+        for (packet, start_of_packet, start_of_message) in examples {
+            let input = TuningTrouble::parse(packet.as_bytes()).unwrap();
+            assert_eq!(TuningTrouble::part_one(&input).unwrap(), start_of_packet);
+            assert_eq!(TuningTrouble::part_two(&input).unwrap(), start_of_message);
+        }
+    }
+
+    #[test]
+    fn rolling_count_matches_naive() {
+        let packets = (
+            prop::collection::vec(prop::char::range('a', 'p'), 0..40),
+            1..15usize,
+        );
+        assert_agree(
+            packets,
+            |(packet, size)| naive::find_start(packet, *size),
+            |(packet, size)| find_start(packet, *size),
+        );
+    }
+}
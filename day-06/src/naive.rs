@@ -0,0 +1,14 @@
+//! The original solver, kept as the reference for differential tests.
+
+use std::collections::HashSet;
+
+pub fn find_start(packet: &[char], size: usize) -> Option<usize> {
+    let mut window: HashSet<char>;
+    for (cnt, marker) in packet.windows(size).enumerate() {
+        window = HashSet::from_iter(marker.iter().copied());
+        if window.len() == size {
+            return Some(cnt + size);
+        }
+    }
+    None
+}
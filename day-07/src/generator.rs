@@ -0,0 +1,67 @@
+use std::io::{self, Write};
+
+use aoc_common::{Generator, Rng};
+
+use crate::{NoSpaceLeftOnDevice, DISK_SIZE, UPDATE_SIZE};
+
+/// `size` is the number of directories. New directories mostly go into the
+/// one created last, so the tree gets deep. File sizes are scaled so the
+/// disk is full enough that the update needs space freed.
+impl Generator for NoSpaceLeftOnDevice {
+    fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+        let dirs = size.max(1);
+        let mut subdirs: Vec<Vec<usize>> = vec![Vec::new(); dirs];
+        for dir in 1..dirs {
+            let parent = if rng.chance(0.7) {
+                dir - 1
+            } else {
+                rng.index(dir)
+            };
+            subdirs[parent].push(dir);
+        }
+
+        let weights: Vec<Vec<u64>> = (0..dirs)
+            .map(|_| {
+                (0..rng.between(0, 4))
+                    .map(|_| rng.between(1, 1000))
+                    .collect()
+            })
+            .collect();
+        let total_weight: u64 = weights.iter().flatten().sum::<u64>().max(1);
+        let used = rng.between((DISK_SIZE - UPDATE_SIZE + 1) as u64, DISK_SIZE as u64);
+
+        writeln!(out, "$ cd /")?;
+        let mut path: Vec<(usize, usize)> = vec![(0, 0)];
+        let mut listed = false;
+        while let Some((dir, next)) = path.last_mut() {
+            let dir = *dir;
+            if !listed {
+                writeln!(out, "$ ls")?;
+                for subdir in &subdirs[dir] {
+                    writeln!(out, "dir d{}", subdir)?;
+                }
+                for (idx, weight) in weights[dir].iter().enumerate() {
+                    let file_size = (weight * used / total_weight).max(1);
+                    writeln!(out, "{} f{}.txt", file_size, idx)?;
+                }
+                listed = true;
+            }
+
+            match subdirs[dir].get(*next) {
+                Some(subdir) => {
+                    *next += 1;
+                    writeln!(out, "$ cd d{}", subdir)?;
+                    path.push((*subdir, 0));
+                    listed = false;
+                }
+                None => {
+                    path.pop();
+                    if !path.is_empty() {
+                        writeln!(out, "$ cd ..")?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+use std::{
+    cell::RefCell,
+    io::Read,
+    rc::{Rc, Weak},
+};
+
+use aoc_common::{input, Day, Diagnostic, Result, Solution};
+
+mod generator;
+
+pub const DAY: Day = Day::new::<NoSpaceLeftOnDevice>(env!("CARGO_MANIFEST_DIR"))
+    .with_generator::<NoSpaceLeftOnDevice>();
+
+pub struct NoSpaceLeftOnDevice;
+
+const DISK_SIZE: u32 = 70_000_000;
+const UPDATE_SIZE: u32 = 30_000_000;
+const SMALL_DIR_LIMIT: u32 = 100_000;
+
+#[derive(Clone)]
+struct DirFile {
+    size: u32,
+}
+
+impl From<&DirFile> for u32 {
+    fn from(file: &DirFile) -> Self {
+        file.size
+    }
+}
+
+pub struct Directory {
+    name: String,
+    parent: Weak<RefCell<Directory>>,
+    files: Vec<DirFile>,
+    subdirs: Vec<Rc<RefCell<Directory>>>,
+}
+
+impl From<&Directory> for u32 {
+    fn from(dir: &Directory) -> Self {
+        let mut total_size: u32 = dir.files.iter().map(u32::from).sum();
+
+        total_size += dir
+            .subdirs
+            .iter()
+            .map(|subdir| u32::from(&*subdir.borrow()))
+            .sum::<u32>();
+        total_size
+    }
+}
+
+trait SizeCalculation {
+    fn calculate_size(&self) -> u32;
+    fn dir_sizes(&self) -> Vec<u32>;
+}
+
+impl SizeCalculation for Directory {
+    fn calculate_size(&self) -> u32 {
+        u32::from(self)
+    }
+
+    fn dir_sizes(&self) -> Vec<u32> {
+        let mut sizes = vec![self.calculate_size()];
+        for subdir in &self.subdirs {
+            sizes.append(&mut subdir.borrow().dir_sizes());
+        }
+        sizes
+    }
+}
+
+trait FileManager {
+    fn get_dir(&self, name: &str) -> Option<Rc<RefCell<Directory>>>;
+    fn mk_dir(this: &Rc<RefCell<Directory>>, name: &str);
+    fn add_file(&mut self, size: u32);
+}
+
+impl FileManager for Directory {
+    fn get_dir(&self, name: &str) -> Option<Rc<RefCell<Directory>>> {
+        if name == ".." {
+            return self.parent.upgrade();
+        }
+        self.subdirs
+            .iter()
+            .find(|dir| dir.borrow().name == name)
+            .cloned()
+    }
+    fn mk_dir(this: &Rc<RefCell<Directory>>, name: &str) {
+        if this.borrow().get_dir(name).is_some() {
+            return;
+        }
+        let dir = Directory {
+            name: name.to_string(),
+            parent: Rc::downgrade(this),
+            files: Vec::new(),
+            subdirs: Vec::new(),
+        };
+        this.borrow_mut().subdirs.push(Rc::new(RefCell::new(dir)));
+    }
+    fn add_file(&mut self, size: u32) {
+        self.files.push(DirFile { size })
+    }
+}
+
+fn parse_file<R: Read>(reader: R) -> Result<Rc<RefCell<Directory>>> {
+    let root_dir = Rc::new(RefCell::new(Directory {
+        name: "/".to_string(),
+        parent: Weak::new(),
+        files: Vec::new(),
+        subdirs: Vec::new(),
+    }));
+    let mut current_dir = Rc::clone(&root_dir);
+
+    for (idx, line) in input::lines(reader)?.iter().enumerate() {
+        let command: Vec<&str> = line.split_whitespace().collect();
+        match command.as_slice() {
+            ["$", "cd", "/"] => current_dir = Rc::clone(&root_dir),
+            ["$", "cd", name] => {
+                let next_dir = current_dir.borrow().get_dir(name).ok_or_else(|| {
+                    Diagnostic::token(line, name, format!("no directory {:?}", name))
+                        .expected("a directory listed before")
+                        .at_line(idx + 1)
+                })?;
+                current_dir = next_dir;
+            }
+            ["$", "ls"] => continue,
+            ["dir", name] => Directory::mk_dir(&current_dir, name),
+            [size, _] => {
+                let size = size.parse().map_err(|_| {
+                    Diagnostic::unexpected(line, size, "a file size").at_line(idx + 1)
+                })?;
+                current_dir.borrow_mut().add_file(size);
+            }
+            _ => {
+                return Err(Diagnostic::token(line, line, "unknown command")
+                    .expected("`$ cd`, `$ ls`, `dir <name>` or `<size> <name>`")
+                    .at_line(idx + 1)
+                    .into())
+            }
+        }
+    }
+    Ok(root_dir)
+}
+
+fn solve_1(root_dir: &Directory) -> u32 {
+    root_dir
+        .dir_sizes()
+        .into_iter()
+        .filter(|size| *size <= SMALL_DIR_LIMIT)
+        .sum()
+}
+
+fn solve_2(root_dir: &Directory) -> u32 {
+    let free_space = DISK_SIZE - root_dir.calculate_size();
+    let needed_space = UPDATE_SIZE.saturating_sub(free_space);
+    root_dir
+        .dir_sizes()
+        .into_iter()
+        .filter(|size| *size >= needed_space)
+        .min()
+        .unwrap_or(0)
+}
+
+impl Solution for NoSpaceLeftOnDevice {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "No Space Left On Device";
+
+    type Input = Rc<RefCell<Directory>>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse<R: Read>(reader: R) -> Result<Self::Input> {
+        parse_file(reader)
+    }
+
+    fn part_one(root_dir: &Self::Input) -> Result<u32> {
+        Ok(solve_1(&root_dir.borrow()))
+    }
+
+    fn part_two(root_dir: &Self::Input) -> Result<u32> {
+        Ok(solve_2(&root_dir.borrow()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../data/example");
+
+    #[test]
+    fn part_one_example() {
+        let input = NoSpaceLeftOnDevice::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(NoSpaceLeftOnDevice::part_one(&input).unwrap(), 95437);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = NoSpaceLeftOnDevice::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(NoSpaceLeftOnDevice::part_two(&input).unwrap(), 24933642);
+    }
+}
@@ -0,0 +1,18 @@
+use std::io::{self, Write};
+
+use aoc_common::{Generator, Rng};
+
+use crate::TreetopTreeHouse;
+
+/// `size` is the side of the square forest.
+impl Generator for TreetopTreeHouse {
+    fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+        let side = size.max(3);
+        for _ in 0..side {
+            let row: Vec<u8> = (0..side).map(|_| b'0' + rng.between(0, 9) as u8).collect();
+            out.write_all(&row)?;
+            writeln!(out)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+use std::io::Read;
+
+use aoc_common::{input, Day, Result, Solution};
+
+mod generator;
+#[cfg(test)]
+mod naive;
+
+pub const DAY: Day =
+    Day::new::<TreetopTreeHouse>(env!("CARGO_MANIFEST_DIR")).with_generator::<TreetopTreeHouse>();
+
+pub struct TreetopTreeHouse;
+
+fn load_data<R: Read>(reader: R) -> Result<Vec<Vec<u8>>> {
+    input::grid(reader, |c| c.to_digit(10).map(|d| d as u8))
+}
+
+/// Which trees of a line can be seen from its start: those taller than
+/// every tree before them.
+fn visible_from_start(line: impl Iterator<Item = u8>) -> Vec<bool> {
+    let mut tallest = None;
+    line.map(|height| {
+        let visible = tallest.is_none_or(|tallest| height > tallest);
+        tallest = tallest.max(Some(height));
+        visible
+    })
+    .collect()
+}
+
+/// How far each tree of a line sees towards its start. A stack keeps the
+/// trees not yet blocked by a taller one, so the first tree at least as
+/// tall is found without rescanning the line.
+fn view_to_start(line: impl Iterator<Item = u8>) -> Vec<u32> {
+    let mut stack: Vec<(usize, u8)> = Vec::new();
+    line.enumerate()
+        .map(|(idx, height)| {
+            while stack.last().is_some_and(|&(_, other)| other < height) {
+                stack.pop();
+            }
+            let distance = match stack.last() {
+                Some(&(blocker, _)) => idx - blocker,
+                None => idx,
+            };
+            stack.push((idx, height));
+            distance as u32
+        })
+        .collect()
+}
+
+/// Applies `sweep` to every row and column of the forest in both
+/// directions and folds the results per tree with `combine`.
+fn sweep_forest<T: Copy>(
+    forest: &[Vec<u8>],
+    init: T,
+    sweep: impl Fn(&mut dyn Iterator<Item = u8>) -> Vec<T>,
+    combine: impl Fn(T, T) -> T,
+) -> Vec<Vec<T>> {
+    let rows = forest.len();
+    let cols = forest.first().map_or(0, Vec::len);
+    let mut result = vec![vec![init; cols]; rows];
+
+    for (row_idx, row) in forest.iter().enumerate() {
+        let forward = sweep(&mut row.iter().copied());
+        let backward = sweep(&mut row.iter().rev().copied());
+        for col_idx in 0..cols {
+            let cell = &mut result[row_idx][col_idx];
+            *cell = combine(*cell, forward[col_idx]);
+            *cell = combine(*cell, backward[cols - 1 - col_idx]);
+        }
+    }
+    for col_idx in 0..cols {
+        let forward = sweep(&mut forest.iter().map(|row| row[col_idx]));
+        let backward = sweep(&mut forest.iter().rev().map(|row| row[col_idx]));
+        for row_idx in 0..rows {
+            let cell = &mut result[row_idx][col_idx];
+            *cell = combine(*cell, forward[row_idx]);
+            *cell = combine(*cell, backward[rows - 1 - row_idx]);
+        }
+    }
+    result
+}
+
+fn look_at_trees(forest: &[Vec<u8>]) -> u32 {
+    sweep_forest(
+        forest,
+        false,
+        |line| visible_from_start(line),
+        |a, b| a || b,
+    )
+    .iter()
+    .flatten()
+    .filter(|visible| **visible)
+    .count() as u32
+}
+
+fn assess_the_trees(forest: &[Vec<u8>]) -> u32 {
+    sweep_forest(forest, 1, |line| view_to_start(line), |a, b| a * b)
+        .iter()
+        .flatten()
+        .copied()
+        .max()
+        .unwrap_or(0)
+}
+
+impl Solution for TreetopTreeHouse {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Treetop Tree House";
+
+    type Input = Vec<Vec<u8>>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse<R: Read>(reader: R) -> Result<Self::Input> {
+        load_data(reader)
+    }
+
+    fn part_one(forest: &Self::Input) -> Result<u32> {
+        Ok(look_at_trees(forest))
+    }
+
+    fn part_two(forest: &Self::Input) -> Result<u32> {
+        Ok(assess_the_trees(forest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{
+        differential::{assert_agree, proptest::prelude::*},
+        Error,
+    };
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../data/example");
+
+    #[test]
+    fn part_one_example() {
+        let input = TreetopTreeHouse::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(TreetopTreeHouse::part_one(&input).unwrap(), 21);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = TreetopTreeHouse::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(TreetopTreeHouse::part_two(&input).unwrap(), 8);
+    }
+
+    #[test]
+    fn ragged_row_is_reported() {
+        let input = EXAMPLE.replacen("35390", "3539", 1);
+        let Err(Error::Parse(err)) = TreetopTreeHouse::parse(input.as_bytes()) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.line, err.column()), (5, 5));
+        assert_eq!(err.message, "the row has 4 cells, the first one has 5");
+    }
+
+    /// Rectangular forests of at least 2x2 trees, which the naive solver
+    /// needs for its edge count.
+    fn forests() -> impl Strategy<Value = Vec<Vec<u8>>> {
+        (2..12usize, 2..12usize).prop_flat_map(|(rows, cols)| {
+            prop::collection::vec(prop::collection::vec(0..10u8, cols), rows)
+        })
+    }
+
+    #[test]
+    fn visibility_matches_naive() {
+        assert_agree(forests(), |f| naive::look_at_trees(f), |f| look_at_trees(f));
+    }
+
+    #[test]
+    fn scenic_score_matches_naive() {
+        assert_agree(
+            forests(),
+            |f| naive::assess_the_trees(f),
+            |f| assess_the_trees(f),
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use std::io::{self, Write};
+
+use aoc_common::{Generator, Rng};
+
+use crate::RopeBridge;
+
+/// `size` is the number of moves.
+impl Generator for RopeBridge {
+    fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+        for _ in 0..size {
+            let direction = rng.pick(&["U", "D", "L", "R"]);
+            writeln!(out, "{} {}", direction, rng.between(1, 20))?;
+        }
+        Ok(())
+    }
+}
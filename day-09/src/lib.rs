@@ -0,0 +1,315 @@
+use std::{
+    cmp::max,
+    collections::HashSet,
+    io::Read,
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+use aoc_common::{input, Day, Diagnostic, Result, Solution, Tokens};
+
+mod generator;
+#[cfg(test)]
+mod naive;
+
+pub const DAY: Day =
+    Day::new::<RopeBridge>(env!("CARGO_MANIFEST_DIR")).with_generator::<RopeBridge>();
+
+pub struct RopeBridge;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl FromStr for Direction {
+    type Err = Diagnostic;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value {
+            "U" => Ok(Direction::Up),
+            "D" => Ok(Direction::Down),
+            "L" => Ok(Direction::Left),
+            "R" => Ok(Direction::Right),
+            _ => Err(Diagnostic::unexpected(
+                value,
+                value,
+                "a direction, U, D, L or R",
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Move {
+    direction: Direction,
+    value: i32,
+}
+
+impl FromStr for Move {
+    type Err = Diagnostic;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        let mut command = Tokens::new(value);
+        let direction = command.read("a direction")?;
+        let steps = command.next("a number of steps")?;
+        let value = steps
+            .parse()
+            .ok()
+            .filter(|steps: &i32| *steps >= 0)
+            .ok_or_else(|| Diagnostic::unexpected(value, steps, "a number of steps"))?;
+        command.end()?;
+        Ok(Move { direction, value })
+    }
+}
+
+impl From<Move> for Position {
+    fn from(move_val: Move) -> Self {
+        match move_val.direction {
+            Direction::Right => Self {
+                x: move_val.value,
+                y: 0,
+            },
+            Direction::Left => Self {
+                x: -move_val.value,
+                y: 0,
+            },
+            Direction::Up => Self {
+                x: 0,
+                y: move_val.value,
+            },
+            Direction::Down => Self {
+                x: 0,
+                y: -move_val.value,
+            },
+        }
+    }
+}
+
+impl Add for Position {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Sub for Position {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl Position {
+    fn new() -> Position {
+        Position { x: 0, y: 0 }
+    }
+
+    /// One step of a knot towards another `diff` away.
+    fn step_towards(diff: Position) -> Position {
+        Position {
+            x: diff.x.signum(),
+            y: diff.y.signum(),
+        }
+    }
+
+    fn set_value(&mut self, value: Position) {
+        self.x = value.x;
+        self.y = value.y;
+    }
+}
+
+trait Moves {
+    fn execute_moves(&mut self, moves: &[Move]);
+}
+
+struct Rope {
+    knots: Vec<Position>,
+    tail_positions: HashSet<Position>,
+}
+
+impl Rope {
+    fn new(knots: Vec<Position>) -> Rope {
+        Rope {
+            knots,
+            tail_positions: HashSet::from([Position::new()]),
+        }
+    }
+
+    fn process_step(&mut self, step: Position) {
+        self.process_head(step);
+        self.process_tail();
+        self.tail_positions.insert(*self.knots.last().unwrap());
+    }
+
+    fn process_head(&mut self, step: Position) {
+        let head = self.knots.get_mut(0).unwrap();
+        head.set_value(*head + step);
+    }
+
+    /// Pulls the rope a whole move at once. With only two knots the tail
+    /// trails straight behind the head, so the head jumps to its end and the
+    /// tail slides after it; longer ropes still go one step at a time.
+    fn pull(&mut self, move_val: &Move) {
+        if self.knots.len() != 2 {
+            let step = Move {
+                direction: move_val.direction,
+                value: 1,
+            };
+            for _ in 0..move_val.value {
+                self.process_step(step.into());
+            }
+            return;
+        }
+
+        self.process_head((*move_val).into());
+        loop {
+            let diff = self.knots[0] - self.knots[1];
+            if max(diff.x.abs(), diff.y.abs()) <= 1 {
+                break;
+            }
+            let tail = &mut self.knots[1];
+            tail.set_value(*tail + Position::step_towards(diff));
+            self.tail_positions.insert(*tail);
+        }
+    }
+
+    fn process_tail(&mut self) {
+        for idx in 1..self.knots.len() {
+            let diff = *self.knots.get(idx - 1).unwrap() - *self.knots.get(idx).unwrap();
+            if max(diff.x.abs(), diff.y.abs()) <= 1 {
+                continue;
+            }
+            let knot = self.knots.get_mut(idx).unwrap();
+            knot.set_value(*knot + Position::step_towards(diff));
+        }
+    }
+}
+
+impl Moves for Rope {
+    fn execute_moves(&mut self, moves: &[Move]) {
+        for move_val in moves {
+            self.pull(move_val);
+        }
+    }
+}
+
+fn load_data<R: Read>(reader: R) -> Result<Vec<Move>> {
+    let mut moves: Vec<Move> = Vec::new();
+    for (idx, line) in input::lines(reader)?.iter().enumerate() {
+        moves.push(
+            line.parse()
+                .map_err(|err: Diagnostic| err.at_line(idx + 1))?,
+        )
+    }
+
+    Ok(moves)
+}
+
+fn visited(moves: &[Move], knots: usize) -> HashSet<Position> {
+    let mut rope = Rope::new(vec![Position::new(); knots]);
+    rope.execute_moves(moves);
+    rope.tail_positions
+}
+
+fn tail_positions(moves: &[Move], knots: usize) -> usize {
+    visited(moves, knots).len()
+}
+
+impl Solution for RopeBridge {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Rope Bridge";
+
+    type Input = Vec<Move>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse<R: Read>(reader: R) -> Result<Self::Input> {
+        load_data(reader)
+    }
+
+    fn part_one(moves: &Self::Input) -> Result<usize> {
+        Ok(tail_positions(moves, 2))
+    }
+
+    fn part_two(moves: &Self::Input) -> Result<usize> {
+        Ok(tail_positions(moves, 10))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{
+        differential::{assert_agree, proptest::prelude::*},
+        Error,
+    };
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../data/example");
+
+    #[test]
+    fn part_one_example() {
+        let input = RopeBridge::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(RopeBridge::part_one(&input).unwrap(), 13);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = RopeBridge::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(RopeBridge::part_two(&input).unwrap(), 1);
+    }
+
+    #[test]
+    fn part_two_larger_example() {
+        let input = RopeBridge::parse(include_str!("../data/example2").as_bytes()).unwrap();
+        assert_eq!(RopeBridge::part_two(&input).unwrap(), 36);
+    }
+
+    fn moves() -> impl Strategy<Value = Vec<Move>> {
+        let direction = prop_oneof![
+            Just(Direction::Up),
+            Just(Direction::Down),
+            Just(Direction::Left),
+            Just(Direction::Right),
+        ];
+        prop::collection::vec(
+            (direction, 1..10i32).prop_map(|(direction, value)| Move { direction, value }),
+            0..20,
+        )
+    }
+
+    #[test]
+    fn bulk_moves_match_naive() {
+        assert_agree(
+            (moves(), 2..=10usize),
+            |(moves, knots)| naive::visited(moves, *knots),
+            |(moves, knots)| visited(moves, *knots),
+        );
+    }
+
+    #[test]
+    fn unknown_direction_is_reported() {
+        let Err(Error::Parse(err)) = RopeBridge::parse("R 4\nX 2\n".as_bytes()) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.line, err.column(), err.text()), (2, 1, "X"));
+    }
+}
@@ -0,0 +1,31 @@
+//! The original solver, kept as the reference for differential tests.
+
+use std::collections::HashSet;
+
+use crate::{Move, Position, Rope};
+
+impl Move {
+    fn get_path(&self) -> Vec<Position> {
+        let mut path = Vec::new();
+        for _ in 1..self.value + 1 {
+            path.push(
+                Move {
+                    direction: self.direction,
+                    value: 1,
+                }
+                .into(),
+            )
+        }
+        path
+    }
+}
+
+pub fn visited(moves: &[Move], knots: usize) -> HashSet<Position> {
+    let mut rope = Rope::new(vec![Position::new(); knots]);
+    for move_val in moves {
+        for step in move_val.get_path() {
+            rope.process_step(step);
+        }
+    }
+    rope.tail_positions
+}
@@ -0,0 +1,19 @@
+use std::io::{self, Write};
+
+use aoc_common::{Generator, Rng};
+
+use crate::CathodeRayTube;
+
+/// `size` is the number of instructions.
+impl Generator for CathodeRayTube {
+    fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+        for _ in 0..size {
+            if rng.chance(0.3) {
+                writeln!(out, "noop")?;
+            } else {
+                writeln!(out, "addx {}", rng.between(0, 40) as i64 - 20)?;
+            }
+        }
+        Ok(())
+    }
+}
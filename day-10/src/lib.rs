@@ -0,0 +1,175 @@
+use std::{collections::VecDeque, io::Read};
+
+use aoc_common::{input, Day, Diagnostic, Result, Solution, Tokens};
+
+mod generator;
+
+pub const DAY: Day =
+    Day::new::<CathodeRayTube>(env!("CARGO_MANIFEST_DIR")).with_generator::<CathodeRayTube>();
+
+pub struct CathodeRayTube;
+
+#[derive(Clone)]
+pub struct Circuit {
+    register: i32,
+    clock: u32,
+    signal_checker: u32,
+    signal_step: u32,
+
+    crt_position: i32,
+    crt_width: i32,
+    crt_row: String,
+    crt_display: String,
+
+    crt_empty_char: char,
+    crt_full_char: char,
+
+    instructions: VecDeque<Option<i32>>,
+}
+
+impl Circuit {
+    fn new() -> Self {
+        Circuit {
+            register: 1,
+            clock: 0,
+
+            signal_checker: 20,
+            signal_step: 40,
+
+            crt_position: 0,
+            crt_width: 40,
+            crt_row: "".to_string(),
+            crt_display: "".to_string(),
+
+            crt_empty_char: ' ',
+            crt_full_char: '█',
+
+            instructions: VecDeque::new(),
+        }
+    }
+
+    fn add(&mut self, val: i32) {
+        self.instructions
+            .append(&mut VecDeque::from([None, Some(val)]));
+    }
+
+    fn draw_crt(&mut self) {
+        let sprite_range = (self.register - 1)..(self.register + 2);
+        if sprite_range.contains(&(self.crt_position)) {
+            self.crt_row.push(self.crt_full_char);
+        } else {
+            self.crt_row.push(self.crt_empty_char);
+        }
+        self.crt_position += 1;
+        if self.crt_position >= self.crt_width {
+            self.crt_display.push_str(self.crt_row.as_str());
+            self.crt_display.push('\n');
+            self.crt_row = "".to_string();
+            self.crt_position = 0;
+        }
+    }
+
+    fn noop(&mut self) {
+        self.instructions.push_back(None);
+    }
+
+    fn signal_strength(&self) -> i32 {
+        self.register * self.clock as i32
+    }
+
+    fn tick(&mut self) {
+        let instruction = self.instructions.pop_front();
+        match instruction.unwrap() {
+            None => (),
+            Some(val) => self.register += val,
+        }
+    }
+
+    fn check_signal(&mut self) -> i32 {
+        if self.clock < self.signal_checker {
+            return 0;
+        }
+        self.signal_checker += self.signal_step;
+        self.signal_strength()
+    }
+
+    fn solve(&mut self) -> i32 {
+        let mut signal_strenght_sum = 0;
+        for _ in 0..self.instructions.len() {
+            self.clock += 1;
+            self.draw_crt();
+            signal_strenght_sum += self.check_signal();
+            self.tick();
+        }
+        signal_strenght_sum
+    }
+}
+
+fn load_instruction(circuit: &mut Circuit, line: &str) -> std::result::Result<(), Diagnostic> {
+    let mut command = Tokens::new(line);
+    match command.next("an instruction")? {
+        "noop" => circuit.noop(),
+        "addx" => circuit.add(command.parse("a number")?),
+        name => return Err(Diagnostic::unexpected(line, name, "noop or addx")),
+    }
+    command.end()
+}
+
+fn load_data<R: Read>(reader: R) -> Result<Circuit> {
+    let mut circuit = Circuit::new();
+    for (idx, line) in input::lines(reader)?.iter().enumerate() {
+        load_instruction(&mut circuit, line).map_err(|err| err.at_line(idx + 1))?;
+    }
+    Ok(circuit)
+}
+
+impl Solution for CathodeRayTube {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Cathode-Ray Tube";
+
+    type Input = Circuit;
+    type PartOne = i32;
+    type PartTwo = String;
+
+    fn parse<R: Read>(reader: R) -> Result<Self::Input> {
+        load_data(reader)
+    }
+
+    fn part_one(circuit: &Self::Input) -> Result<i32> {
+        Ok(circuit.clone().solve())
+    }
+
+    fn part_two(circuit: &Self::Input) -> Result<String> {
+        let mut circuit = circuit.clone();
+        circuit.solve();
+        Ok(circuit.crt_display)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../data/example");
+
+    const EXAMPLE_DISPLAY: &str = concat!(
+        "██  ██  ██  ██  ██  ██  ██  ██  ██  ██  \n",
+        "███   ███   ███   ███   ███   ███   ███ \n",
+        "████    ████    ████    ████    ████    \n",
+        "█████     █████     █████     █████     \n",
+        "██████      ██████      ██████      ████\n",
+        "███████       ███████       ███████     \n",
+    );
+
+    #[test]
+    fn part_one_example() {
+        let input = CathodeRayTube::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(CathodeRayTube::part_one(&input).unwrap(), 13140);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = CathodeRayTube::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(CathodeRayTube::part_two(&input).unwrap(), EXAMPLE_DISPLAY);
+    }
+}
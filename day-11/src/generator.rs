@@ -0,0 +1,75 @@
+use std::io::{self, Write};
+
+use aoc_common::{Generator, Rng, Solution};
+
+use crate::MonkeyInTheMiddle;
+
+/// Notes drawn with multiplications before falling back to additions only.
+const ATTEMPTS: usize = 8;
+
+/// Small primes keep the lcm of every test well inside a u32, so part two's
+/// worry levels, kept below it, still fit a u64 once squared. Part one has
+/// no such bound; see [`MonkeyInTheMiddle::generate`].
+const DIVISORS: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+fn other_monke(rng: &mut Rng, monkes: usize, not: &[usize]) -> usize {
+    loop {
+        let monke = rng.index(monkes);
+        if !not.contains(&monke) {
+            return monke;
+        }
+    }
+}
+
+/// Notes for `monkes` monkeys; without `multiply`, every operation adds.
+fn notes(rng: &mut Rng, monkes: usize, multiply: bool) -> String {
+    let squaring = rng.index(monkes);
+    let mut notes = Vec::new();
+
+    for monke in 0..monkes {
+        let items: Vec<String> = (0..rng.between(1, 8))
+            .map(|_| rng.between(50, 99).to_string())
+            .collect();
+        let operation = if multiply && monke == squaring {
+            "old * old".to_string()
+        } else if multiply && rng.chance(0.5) {
+            format!("old * {}", rng.between(2, 19))
+        } else {
+            format!("old + {}", rng.between(1, 8))
+        };
+        let on_true = other_monke(rng, monkes, &[monke]);
+        let on_false = other_monke(rng, monkes, &[monke, on_true]);
+
+        notes.push(format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  \
+             Test: divisible by {}\n    If true: throw to monkey {}\n    \
+             If false: throw to monkey {}\n",
+            monke,
+            items.join(", "),
+            operation,
+            rng.pick(&DIVISORS),
+            on_true,
+            on_false
+        ));
+    }
+    notes.join("\n")
+}
+
+/// `size` is the number of monkeys. Like the real notes, at most one of
+/// them squares the worry level. Part one only divides it by three, so
+/// notes whose levels outgrow a u64 there are drawn again, and the last
+/// resort only adds, which divided by three can't grow.
+impl Generator for MonkeyInTheMiddle {
+    fn generate(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+        let monkes = size.max(3);
+        for attempt in 0..=ATTEMPTS {
+            let notes = notes(rng, monkes, attempt < ATTEMPTS);
+            let solved = MonkeyInTheMiddle::parse(notes.as_bytes())
+                .and_then(|barrel| MonkeyInTheMiddle::part_one(&barrel));
+            if solved.is_ok() || attempt == ATTEMPTS {
+                return out.write_all(notes.as_bytes());
+            }
+        }
+        unreachable!("the last attempt is always written")
+    }
+}
@@ -0,0 +1,304 @@
+use std::{collections::VecDeque, fmt::Debug, io::Read, str::FromStr};
+
+use aoc_common::{Day, Diagnostic, Error, Result, Solution, Tokens};
+mod generator;
+mod loader;
+mod math;
+use loader::load_data;
+use math::lcm;
+
+pub const DAY: Day =
+    Day::new::<MonkeyInTheMiddle>(env!("CARGO_MANIFEST_DIR")).with_generator::<MonkeyInTheMiddle>();
+
+pub struct MonkeyInTheMiddle;
+
+#[derive(Debug, Clone, Copy)]
+enum Operator {
+    Addition,
+    Multiplication,
+}
+
+impl FromStr for Operator {
+    type Err = Diagnostic;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value {
+            "+" => Ok(Operator::Addition),
+            "*" => Ok(Operator::Multiplication),
+            _ => Err(Diagnostic::unexpected(value, value, "+ or *")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Operation {
+    value_l: Option<u64>,
+    operator: Operator,
+    value_r: Option<u64>,
+}
+
+impl Operation {
+    /// `None` if the worry level overflows.
+    fn result(&self, val: u64) -> Option<u64> {
+        let left = self.value_l.unwrap_or(val);
+        let right = self.value_r.unwrap_or(val);
+        match self.operator {
+            Operator::Addition => left.checked_add(right),
+            Operator::Multiplication => left.checked_mul(right),
+        }
+    }
+}
+
+/// `old`, which is `None`, or a constant.
+fn operand(words: &mut Tokens, source: &str) -> std::result::Result<Option<u64>, Diagnostic> {
+    match words.next("old or a number")? {
+        "old" => Ok(None),
+        val => val
+            .parse()
+            .map(Some)
+            .map_err(|_| Diagnostic::unexpected(source, val, "old or a number")),
+    }
+}
+
+impl FromStr for Operation {
+    type Err = Diagnostic;
+
+    fn from_str(val: &str) -> std::result::Result<Self, Self::Err> {
+        let mut words = Tokens::new(val);
+        words.keyword("new")?;
+        words.keyword("=")?;
+        let operation = Self {
+            value_l: operand(&mut words, val)?,
+            operator: words.read("+ or *")?,
+            value_r: operand(&mut words, val)?,
+        };
+        words.end()?;
+        Ok(operation)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Tests {
+    Divisble,
+}
+
+impl FromStr for Tests {
+    type Err = Diagnostic;
+
+    fn from_str(val: &str) -> std::result::Result<Self, Self::Err> {
+        match val {
+            "divisible" => Ok(Tests::Divisble),
+            _ => Err(Diagnostic::unexpected(val, val, "divisible")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Test {
+    test: Tests,
+    value: u64,
+    on_true: usize,
+    on_false: usize,
+}
+
+impl Test {
+    fn perform(&self, value: u64) -> usize {
+        match self.test {
+            Tests::Divisble => self.divisble(value),
+        }
+    }
+
+    fn divisble(&self, value: u64) -> usize {
+        if value.is_multiple_of(self.value) {
+            return self.on_true;
+        }
+        self.on_false
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Relief {
+    /// Worry drops to a third after every inspection.
+    Bored(u64),
+    /// Worry is kept below the lcm of every test after each inspection.
+    Therapy(u64),
+}
+
+#[derive(Clone)]
+pub struct Monke {
+    name: u64,
+    items: VecDeque<u64>,
+    operation: Operation,
+    test: Test,
+    examinations: u64,
+}
+
+impl Monke {
+    fn examine(&mut self, relief: Relief) -> Result<(usize, u64)> {
+        self.examinations += 1;
+        let mut new_level = self
+            .operation
+            .result(self.items.pop_front().unwrap())
+            .ok_or_else(|| {
+                Error::invalid(format!(
+                    "monkey {} makes a worry level too big for a u64",
+                    self.name
+                ))
+            })?;
+        match relief {
+            Relief::Bored(divisor) => new_level /= divisor,
+            Relief::Therapy(therapy_lcm) => new_level %= therapy_lcm,
+        }
+        let next_monke = self.test.perform(new_level);
+        Ok((next_monke, new_level))
+    }
+}
+
+impl Debug for Monke {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Monke")
+            .field("name", &self.name)
+            .field("examinations", &self.examinations)
+            .finish()
+    }
+}
+
+fn get_lcm(barrel: &[Monke]) -> Result<u64> {
+    let divisors: Vec<u64> = barrel.iter().map(|m| m.test.value).collect();
+    lcm(divisors).ok_or_else(|| Error::invalid("the tests' lcm is too big for a u64"))
+}
+
+fn monke_business(barrel: &[Monke], rounds: usize, relief: Relief) -> Result<u64> {
+    let mut barrel = barrel.to_vec();
+    for _ in 0..rounds {
+        shenanigans(&mut barrel, relief)?;
+    }
+    barrel.sort_by_key(|m| std::cmp::Reverse(m.examinations));
+    barrel
+        .iter()
+        .take(2)
+        .try_fold(1u64, |product, m| product.checked_mul(m.examinations))
+        .ok_or_else(|| Error::invalid("the monkey business is too big for a u64"))
+}
+
+impl Solution for MonkeyInTheMiddle {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Monkey in the Middle";
+
+    type Input = Vec<Monke>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse<R: Read>(reader: R) -> Result<Self::Input> {
+        load_data(reader)
+    }
+
+    fn part_one(barrel: &Self::Input) -> Result<u64> {
+        monke_business(barrel, 20, Relief::Bored(3))
+    }
+
+    fn part_two(barrel: &Self::Input) -> Result<u64> {
+        let therapy_lcm = get_lcm(barrel)?;
+        monke_business(barrel, 10_000, Relief::Therapy(therapy_lcm))
+    }
+}
+
+fn shenanigans(barrel: &mut [Monke], relief: Relief) -> Result<()> {
+    for idx in 0..barrel.len() {
+        let mut passed_items: Vec<(usize, u64)> = Vec::new();
+
+        {
+            let monke = barrel.get_mut(idx).unwrap();
+            for _ in 0..monke.items.len() {
+                let monke_pass = monke.examine(relief)?;
+                passed_items.push(monke_pass);
+            }
+        }
+
+        for (monke_to_pass, item) in passed_items {
+            barrel.get_mut(monke_to_pass).unwrap().items.push_back(item);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../data/example");
+
+    #[test]
+    fn part_one_example() {
+        let input = MonkeyInTheMiddle::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(MonkeyInTheMiddle::part_one(&input).unwrap(), 10605);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = MonkeyInTheMiddle::parse(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(MonkeyInTheMiddle::part_two(&input).unwrap(), 2713310158);
+    }
+
+    #[test]
+    fn unknown_operator_is_reported() {
+        let input = EXAMPLE.replace("old * 19", "old ^ 19");
+        let Err(Error::Parse(err)) = MonkeyInTheMiddle::parse(input.as_bytes()) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.line, err.column(), err.text()), (3, 24, "^"));
+    }
+
+    fn parse_error(input: &str) -> (usize, String) {
+        match MonkeyInTheMiddle::parse(input.as_bytes()) {
+            Err(Error::Parse(err)) => (err.line, err.text().to_string()),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn bad_monkeys_are_reported() {
+        let far = EXAMPLE.replace("throw to monkey 3", "throw to monkey 9");
+        assert_eq!(parse_error(&far), (6, "9".to_string()));
+        let zero = EXAMPLE.replace("divisible by 23", "divisible by 0");
+        assert_eq!(parse_error(&zero), (4, "0".to_string()));
+        assert!(MonkeyInTheMiddle::parse("".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn missing_lines_are_reported() {
+        let lines = ["Starting items", "Operation", "Test", "If true", "If false"];
+        for prefix in lines {
+            let mut paragraphs: Vec<&str> = EXAMPLE.split("\n\n").collect();
+            let second: Vec<&str> = paragraphs[1]
+                .lines()
+                .filter(|line| !line.trim_start().starts_with(prefix))
+                .collect();
+            let second = second.join("\n");
+            paragraphs[1] = &second;
+            let input = paragraphs.join("\n\n");
+
+            let Err(Error::Parse(err)) = MonkeyInTheMiddle::parse(input.as_bytes()) else {
+                panic!("expected a parse error without {}", prefix);
+            };
+            assert_eq!((err.line, err.source.as_str()), (8, "Monkey 1:"));
+            assert_eq!(err.message, format!("missing the {:?} line", prefix));
+        }
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let monke = |name: usize, other: usize| {
+            format!(
+                "Monkey {}:\n  Starting items: 99\n  Operation: new = old * old\n  \
+                 Test: divisible by 2\n    If true: throw to monkey {}\n    \
+                 If false: throw to monkey {}\n",
+                name, other, other
+            )
+        };
+        let input = format!("{}\n{}", monke(0, 1), monke(1, 0));
+        let barrel = MonkeyInTheMiddle::parse(input.as_bytes()).unwrap();
+        let err = MonkeyInTheMiddle::part_one(&barrel).unwrap_err();
+        assert!(err.to_string().contains("too big for a u64"), "{}", err);
+    }
+}
@@ -1 +1,188 @@
-fn load_data(path: &str) -> Vec<Monke> {
+use std::{collections::VecDeque, io::Read};
+
+use aoc_common::{input, Diagnostic, Error, Result, Tokens};
+
+use crate::{Monke, Operation, Operator, Test, Tests};
+
+type ParseResult<T> = std::result::Result<T, Diagnostic>;
+
+/// Parses `part` of `line` word by word, pointing errors at the whole line.
+fn parse_part<'a, T>(
+    line: &str,
+    part: &'a str,
+    parse: impl FnOnce(&mut Tokens<'a>) -> ParseResult<T>,
+) -> ParseResult<T> {
+    let mut words = Tokens::new(part);
+    parse(&mut words)
+        .and_then(|value| words.end().map(|()| value))
+        .map_err(|err| err.within(line, part))
+}
+
+/// The lines of a monkey's notes, each of which has to be there.
+const NOTES: [&str; 6] = [
+    "Monkey",
+    "Starting items",
+    "Operation",
+    "Test",
+    "If true",
+    "If false",
+];
+
+/// Which line of [`NOTES`] was read. A throw keeps the monkey it passes to,
+/// so it can be checked once every monkey is known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Note {
+    Name,
+    Items,
+    Operation,
+    Test,
+    Throw(bool, usize),
+}
+
+impl Note {
+    fn index(self) -> usize {
+        match self {
+            Note::Name => 0,
+            Note::Items => 1,
+            Note::Operation => 2,
+            Note::Test => 3,
+            Note::Throw(true, _) => 4,
+            Note::Throw(false, _) => 5,
+        }
+    }
+}
+
+fn load_line(monke: &mut Monke, line: &str) -> ParseResult<Note> {
+    let (command, data) = line
+        .split_once(':')
+        .ok_or_else(|| Diagnostic::missing(line, "\":\""))?;
+
+    let note = match command.split_whitespace().next().unwrap_or(command) {
+        "Monkey" => {
+            monke.name = parse_part(line, command, |words| {
+                words.keyword("Monkey")?;
+                words.parse("the monkey's number")
+            })?;
+            parse_part(line, data, |_| Ok(()))?;
+            Note::Name
+        }
+        "Starting" => {
+            parse_part(line, command, |words| {
+                words.keyword("Starting")?;
+                words.keyword("items")
+            })?;
+            monke.items = data
+                .split(',')
+                .map(|item| {
+                    let item = item.trim();
+                    item.parse()
+                        .map_err(|_| Diagnostic::unexpected(line, item, "a worry level"))
+                })
+                .collect::<ParseResult<VecDeque<u64>>>()?;
+            Note::Items
+        }
+        "Operation" => {
+            parse_part(line, command, |words| words.keyword("Operation"))?;
+            monke.operation = data
+                .parse()
+                .map_err(|err: Diagnostic| err.within(line, data))?;
+            Note::Operation
+        }
+        "Test" => {
+            parse_part(line, command, |words| words.keyword("Test"))?;
+            (monke.test.test, monke.test.value) = parse_part(line, data, |words| {
+                let test = words.read("a test")?;
+                words.keyword("by")?;
+                Ok((test, words.parse("a number")?))
+            })?;
+            if monke.test.value == 0 {
+                let zero = data.split_whitespace().last().unwrap_or(data);
+                return Err(Diagnostic::unexpected(line, zero, "a number above 0"));
+            }
+            Note::Test
+        }
+        "If" => {
+            let outcome = parse_part(line, command, |words| {
+                words.keyword("If")?;
+                words.next("true or false")
+            })?;
+            let value: usize = parse_part(line, data, |words| {
+                words.keyword("throw")?;
+                words.keyword("to")?;
+                words.keyword("monkey")?;
+                words.parse("a monkey's number")
+            })?;
+            match outcome {
+                "true" => monke.test.on_true = value,
+                "false" => monke.test.on_false = value,
+                _ => return Err(Diagnostic::unexpected(line, outcome, "true or false")),
+            }
+            Note::Throw(outcome == "true", value)
+        }
+        other => {
+            return Err(Diagnostic::unexpected(
+                line,
+                other,
+                "Monkey, Starting, Operation, Test or If",
+            ))
+        }
+    };
+    Ok(note)
+}
+
+pub fn load_data<R: Read>(reader: R) -> Result<Vec<Monke>> {
+    let mut barrel: Vec<Monke> = Vec::new();
+    let mut throws: Vec<(usize, String, usize)> = Vec::new();
+
+    for monke_lines in input::numbered_paragraphs(reader)? {
+        let mut monke = Monke {
+            name: 0,
+            items: VecDeque::new(),
+            operation: Operation {
+                value_l: None,
+                value_r: None,
+                operator: Operator::Addition,
+            },
+            test: Test {
+                test: Tests::Divisble,
+                value: 0,
+                on_true: 0,
+                on_false: 0,
+            },
+            examinations: 0,
+        };
+        let mut seen = [false; NOTES.len()];
+        for (number, line) in &monke_lines {
+            let note = load_line(&mut monke, line).map_err(|err| err.at_line(*number))?;
+            seen[note.index()] = true;
+            if let Note::Throw(_, target) = note {
+                throws.push((*number, line.clone(), target));
+            }
+        }
+        if let Some((missing, _)) = NOTES.iter().zip(seen).find(|&(_, seen)| !seen) {
+            let (number, header) = &monke_lines[0];
+            return Err(
+                Diagnostic::missing(header, format!("the {:?} line", missing))
+                    .at_line(*number)
+                    .into(),
+            );
+        }
+        barrel.push(monke)
+    }
+
+    if barrel.is_empty() {
+        return Err(Error::invalid("there are no monkeys"));
+    }
+    if let Some((number, line, _)) = throws.iter().find(|(_, _, target)| *target >= barrel.len()) {
+        let target = line.split_whitespace().last().unwrap_or(line);
+        return Err(Diagnostic::token(
+            line,
+            target,
+            format!("there are only {} monkeys", barrel.len()),
+        )
+        .at_line(*number)
+        .into());
+    }
+
+    Ok(barrel)
+}
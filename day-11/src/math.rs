@@ -6,10 +6,9 @@ fn gcd(a: u64, b: u64) -> u64 {
     }
 }
 
-pub fn lcm(numbers: Vec<u64>) -> u64 {
-    let mut lcm = numbers[0];
-    for i in 1..numbers.len() {
-        lcm = lcm * numbers[i] / gcd(lcm, numbers[i]);
-    }
-    lcm
+/// 1 for no numbers, `None` if it doesn't fit a u64.
+pub fn lcm(numbers: Vec<u64>) -> Option<u64> {
+    numbers.iter().try_fold(1u64, |lcm, &number| {
+        (lcm / gcd(lcm, number)).checked_mul(number)
+    })
 }